@@ -480,6 +480,115 @@ where
     }
 }
 
+pub struct SlidingMinWindow<'a, T: NativeType + PartialOrd + IsFloat> {
+    inner: ExtremumDeque<'a, T>,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNoNulls<'a, T>
+    for SlidingMinWindow<'a, T>
+{
+    fn new(slice: &'a [T], start: usize, end: usize) -> Self {
+        Self {
+            // safety: the window offsets are in bounds
+            inner: unsafe {
+                ExtremumDeque::new(slice, None, start, end, compare_fn_nan_min, Ordering::Less)
+            },
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        // windows without nulls are never empty
+        self.inner.update(start, end).unwrap_unchecked()
+    }
+}
+
+pub struct SlidingMaxWindow<'a, T: NativeType + PartialOrd + IsFloat> {
+    inner: ExtremumDeque<'a, T>,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNoNulls<'a, T>
+    for SlidingMaxWindow<'a, T>
+{
+    fn new(slice: &'a [T], start: usize, end: usize) -> Self {
+        Self {
+            // safety: the window offsets are in bounds
+            inner: unsafe {
+                ExtremumDeque::new(
+                    slice,
+                    None,
+                    start,
+                    end,
+                    compare_fn_nan_max,
+                    Ordering::Greater,
+                )
+            },
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        // windows without nulls are never empty
+        self.inner.update(start, end).unwrap_unchecked()
+    }
+}
+
+/// Same as [`rolling_min`], but guaranteed O(n) by keeping a monotonic deque of the
+/// window candidates. Weighted windows are not monotonic and fall back to [`rolling_min`].
+pub fn sliding_min<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + NumCast + Mul<Output = T> + Bounded + IsFloat,
+{
+    match (center, weights) {
+        (_, Some(_)) => rolling_min(values, window_size, min_periods, center, weights),
+        (true, None) => rolling_apply_agg_window::<SlidingMinWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        ),
+        (false, None) => rolling_apply_agg_window::<SlidingMinWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets,
+        ),
+    }
+}
+
+/// Same as [`rolling_max`], but guaranteed O(n) by keeping a monotonic deque of the
+/// window candidates. Weighted windows are not monotonic and fall back to [`rolling_max`].
+pub fn sliding_max<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
+{
+    match (center, weights) {
+        (_, Some(_)) => rolling_max(values, window_size, min_periods, center, weights),
+        (true, None) => rolling_apply_agg_window::<SlidingMaxWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        ),
+        (false, None) => rolling_apply_agg_window::<SlidingMaxWindow<_>, _, _>(
+            values,
+            window_size,
+            min_periods,
+            det_offsets,
+        ),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -552,4 +661,85 @@ mod test {
             )
         );
     }
+
+    fn naive_min_max(
+        values: &[f64],
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+        min: bool,
+    ) -> Vec<Option<f64>> {
+        let len = values.len();
+        (0..len)
+            .map(|idx| {
+                let (start, end) = if center {
+                    det_offsets_center(idx, window_size, len)
+                } else {
+                    det_offsets(idx, window_size, len)
+                };
+                let window = values[start..end].iter();
+                let out = if min {
+                    window.min_by(|a, b| compare_fn_nan_min(*a, *b))
+                } else {
+                    window.max_by(|a, b| compare_fn_nan_max(*a, *b))
+                };
+                out.copied().filter(|_| end - start >= min_periods)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sliding_min_max() {
+        // pseudo random values with plenty of duplicates
+        let mut state = 7u64;
+        let values = (0..200)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                ((state >> 33) % 17) as f64
+            })
+            .collect::<Vec<_>>();
+
+        for window_size in [1, 2, 3, 7, 16] {
+            for min_periods in [1, window_size] {
+                for center in [false, true] {
+                    let out = sliding_min(&values, window_size, min_periods, center, None);
+                    let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                    let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+                    assert_eq!(
+                        out,
+                        naive_min_max(&values, window_size, min_periods, center, true)
+                    );
+
+                    let out = sliding_max(&values, window_size, min_periods, center, None);
+                    let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                    let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+                    assert_eq!(
+                        out,
+                        naive_min_max(&values, window_size, min_periods, center, false)
+                    );
+                }
+            }
+        }
+
+        // nan dominates the window like in `rolling_min`/`rolling_max`
+        let values = &[1.0, 2.0, 3.0, f64::nan(), 5.0, 6.0, 7.0];
+        let out = sliding_min(values, 3, 3, false, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            format!("{:?}", out.as_slice()),
+            format!(
+                "{:?}",
+                &[
+                    None,
+                    None,
+                    Some(1.0),
+                    Some(f64::nan()),
+                    Some(f64::nan()),
+                    Some(f64::nan()),
+                    Some(5.0)
+                ]
+            )
+        );
+    }
 }
@@ -93,6 +93,10 @@ where
         .map(|idx| {
             let (start, end) = det_offsets_fn(idx, window_size, len);
             let vals = unsafe { values.get_unchecked(start..end) };
+            // a window that is truncated at the start of the array is aligned with the last
+            // weights; the unbounded end tells how many leading positions were cut off
+            let (_, unbounded_end) = det_offsets_fn(idx, window_size, usize::MAX);
+            let weights = &weights[window_size - (unbounded_end - start)..];
 
             aggregator(vals, weights)
         })
@@ -130,7 +134,9 @@ pub(crate) fn compute_mean_weights<T>(values: &[T], weights: &[T]) -> T
 where
     T: Float + std::iter::Sum<T>,
 {
-    values.iter().zip(weights).map(|(v, w)| *v * *w).sum::<T>() / T::from(values.len()).unwrap()
+    // normalize by the weights that are used, windows at the boundaries may be shorter
+    let weight_sum = weights.iter().take(values.len()).copied().sum::<T>();
+    values.iter().zip(weights).map(|(v, w)| *v * *w).sum::<T>() / weight_sum
 }
 
 pub(crate) fn compute_sum_weights<T>(values: &[T], weights: &[T]) -> T
@@ -41,8 +41,12 @@ where
         + NumCast
         + Div<Output = T>,
 {
-    if weights.is_some() {
-        panic!("weights not yet supported on array with null values")
+    if let Some(weights) = weights {
+        return if center {
+            rolling_mean_weights(arr, window_size, min_periods, det_offsets_center, weights)
+        } else {
+            rolling_mean_weights(arr, window_size, min_periods, det_offsets, weights)
+        };
     }
     if center {
         rolling_apply_agg_window::<MeanWindow<_>, _, _>(
@@ -62,3 +66,58 @@ where
         )
     }
 }
+
+/// Weighted mean of every window, where the weights of the null values are left out of the
+/// normalization.
+fn rolling_mean_weights<T, Fo>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+    weights: &[f64],
+) -> ArrayRef
+where
+    T: NativeType + NumCast,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+{
+    assert_eq!(weights.len(), window_size);
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    let len = values.len();
+
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = det_offsets_fn(idx, window_size, len);
+            // a window that is truncated at the start of the array is aligned with the last
+            // weights; the unbounded end tells how many leading positions were cut off
+            let (_, unbounded_end) = det_offsets_fn(idx, window_size, usize::MAX);
+            let weights = &weights[window_size - (unbounded_end - start)..];
+            let mut sum = 0.0;
+            let mut weight_sum = 0.0;
+            let mut count = 0;
+            for (i, w) in (start..end).zip(weights) {
+                // Safety: the offsets are in bounds
+                if unsafe { validity.get_bit_unchecked(i) } {
+                    let v: f64 = NumCast::from(unsafe { *values.get_unchecked(i) }).unwrap();
+                    sum += v * w;
+                    weight_sum += w;
+                    count += 1;
+                }
+            }
+            let valid = count >= min_periods && count > 0 && weight_sum != 0.0;
+            out_validity.push(valid);
+            if valid {
+                NumCast::from(sum / weight_sum).unwrap()
+            } else {
+                T::default()
+            }
+        })
+        .collect_trusted::<Vec<T>>();
+
+    Box::new(PrimitiveArray::new(
+        T::PRIMITIVE.into(),
+        out.into(),
+        Some(out_validity.into()),
+    ))
+}
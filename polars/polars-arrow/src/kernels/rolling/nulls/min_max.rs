@@ -432,3 +432,108 @@ where
         )
     }
 }
+
+pub struct SlidingMinMaxWindow<'a, T: NativeType + PartialOrd + IsFloat, const MIN: bool> {
+    inner: ExtremumDeque<'a, T>,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd, const MIN: bool> RollingAggWindowNulls<'a, T>
+    for SlidingMinMaxWindow<'a, T, MIN>
+{
+    unsafe fn new(slice: &'a [T], validity: &'a Bitmap, start: usize, end: usize) -> Self {
+        let inner = if MIN {
+            ExtremumDeque::new(
+                slice,
+                Some(validity),
+                start,
+                end,
+                compare_fn_nan_min,
+                Ordering::Less,
+            )
+        } else {
+            ExtremumDeque::new(
+                slice,
+                Some(validity),
+                start,
+                end,
+                compare_fn_nan_max,
+                Ordering::Greater,
+            )
+        };
+        Self { inner }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        self.inner.update(start, end)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        (self.inner.window_len() - self.inner.null_count) >= min_periods
+    }
+}
+
+fn sliding_min_max<T, const MIN: bool>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if center {
+        rolling_apply_agg_window::<SlidingMinMaxWindow<_, MIN>, _, _>(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            det_offsets_center,
+        )
+    } else {
+        rolling_apply_agg_window::<SlidingMinMaxWindow<_, MIN>, _, _>(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            det_offsets,
+        )
+    }
+}
+
+/// Same as [`rolling_min`], but guaranteed O(n) by keeping a monotonic deque of the
+/// window candidates.
+pub fn sliding_min<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    sliding_min_max::<_, true>(arr, window_size, min_periods, center)
+}
+
+/// Same as [`rolling_max`], but guaranteed O(n) by keeping a monotonic deque of the
+/// window candidates.
+pub fn sliding_max<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+) -> ArrayRef
+where
+    T: NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    sliding_min_max::<_, false>(arr, window_size, min_periods, center)
+}
@@ -158,6 +158,81 @@ mod test {
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(0.0), Some(4.0 / 3.0)]);
     }
 
+    #[test]
+    fn test_rolling_mean_weights_nulls() {
+        let arr = get_null_arr();
+        let arr = &arr;
+
+        let out = rolling_mean(arr, 2, 1, false, Some(&[1.0, 3.0]));
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(-1.0), Some(2.75)]);
+
+        let out = rolling_mean(arr, 2, 2, false, Some(&[1.0, 3.0]));
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(2.75)]);
+
+        // the leading windows are truncated and use the last weights
+        let out = rolling_mean(arr, 3, 1, false, Some(&[1.0, 2.0, 4.0]));
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(-0.6), Some(7.0 / 3.0)]);
+
+        let out = rolling_mean(arr, 3, 1, true, Some(&[1.0, 2.0, 4.0]));
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1.0), Some(-0.6), Some(7.0 / 3.0), Some(7.0 / 3.0)]
+        );
+
+        let no_nulls = crate::kernels::rolling::no_nulls::rolling_mean(
+            &[1.0, 2.0, 3.0],
+            2,
+            1,
+            false,
+            Some(&[1.0, 3.0]),
+        );
+        let out = no_nulls
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.75), Some(2.75)]);
+    }
+
+    #[test]
+    fn test_rolling_mean_weights_nulls_matches_no_nulls() {
+        let values = vec![1.0, 2.0, -1.0, 4.0, 3.0];
+        let weights = [1.0, 2.0, 4.0];
+        let arr = PrimitiveArray::new(
+            DataType::Float64,
+            Buffer::from(values.clone()),
+            Some(Bitmap::from(&[true; 5])),
+        );
+
+        for center in [false, true] {
+            let out = rolling_mean(&arr, 3, 1, center, Some(&weights));
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+
+            let expected = crate::kernels::rolling::no_nulls::rolling_mean(
+                &values,
+                3,
+                1,
+                center,
+                Some(&weights),
+            );
+            let expected = expected
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            let expected = expected.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+            assert_eq!(out, expected);
+        }
+    }
+
     #[test]
     fn test_rolling_var_nulls() {
         let arr = get_null_arr();
@@ -246,4 +321,58 @@ mod test {
             &[3, 10, 10, 10, 10, 10, 9, 8, 7, 6, 5, 4, 3]
         );
     }
+
+    #[test]
+    fn test_sliding_min_max_nulls() {
+        let values = vec![3.0, 0.0, 1.0, 4.0, 1.0, 5.0, 0.0, 2.0, 6.0, 5.0, 3.0, 5.0];
+        let validity = [
+            true, false, true, true, false, true, true, true, false, false, true, true,
+        ];
+        let arr = &PrimitiveArray::new(
+            DataType::Float64,
+            Buffer::from(values),
+            Some(Bitmap::from(&validity)),
+        );
+
+        // naive O(n * window_size) reference
+        let naive = |window_size: usize, min_periods: usize, center: bool, min: bool| {
+            let len = arr.len();
+            (0..len)
+                .map(|idx| {
+                    let (start, end) = if center {
+                        det_offsets_center(idx, window_size, len)
+                    } else {
+                        det_offsets(idx, window_size, len)
+                    };
+                    let window = (start..end).filter_map(|i| arr.get(i)).collect::<Vec<_>>();
+                    if window.len() < min_periods {
+                        return None;
+                    }
+                    window.into_iter().reduce(|a, b| {
+                        if (min && b < a) || (!min && b > a) {
+                            b
+                        } else {
+                            a
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for window_size in [1, 2, 3, 5] {
+            for min_periods in [1, window_size] {
+                for center in [false, true] {
+                    let out = sliding_min(arr, window_size, min_periods, center, None);
+                    let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                    let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+                    assert_eq!(out, naive(window_size, min_periods, center, true));
+
+                    let out = sliding_max(arr, window_size, min_periods, center, None);
+                    let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                    let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+                    assert_eq!(out, naive(window_size, min_periods, center, false));
+                }
+            }
+        }
+    }
 }
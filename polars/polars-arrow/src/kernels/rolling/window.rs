@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use super::*;
 
 pub(super) struct SortedBuf<'a, T: NativeType + IsFloat + PartialOrd> {
@@ -224,6 +226,103 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> SortedBufNulls<'a, T> {
     }
 }
 
+/// A window that keeps the indices of the candidates for the minimum (or maximum) in a
+/// monotonic deque. Every value enters and leaves the deque at most once, so sliding
+/// over the whole array is O(n) regardless of the window size.
+pub(super) struct ExtremumDeque<'a, T: NativeType + IsFloat + PartialOrd> {
+    // slice over which the window slides
+    slice: &'a [T],
+    validity: Option<&'a Bitmap>,
+    last_start: usize,
+    last_end: usize,
+    // indices into `slice`, the value at the front is the extremum of the window
+    deque: VecDeque<usize>,
+    compare_fn_nan: fn(&T, &T) -> Ordering,
+    // ordering of the extremum relative to the other values, e.g. `Less` for the minimum
+    agg_ordering: Ordering,
+    pub null_count: usize,
+}
+
+impl<'a, T: NativeType + IsFloat + PartialOrd> ExtremumDeque<'a, T> {
+    /// # Safety
+    /// The caller must ensure that `start` and `end` are within bounds of `slice` and `validity`
+    pub(super) unsafe fn new(
+        slice: &'a [T],
+        validity: Option<&'a Bitmap>,
+        start: usize,
+        end: usize,
+        compare_fn_nan: fn(&T, &T) -> Ordering,
+        agg_ordering: Ordering,
+    ) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            last_start: start,
+            last_end: start,
+            deque: VecDeque::with_capacity(end - start),
+            compare_fn_nan,
+            agg_ordering,
+            null_count: 0,
+        };
+        out.update(start, end);
+        out
+    }
+
+    #[inline]
+    unsafe fn is_null(&self, idx: usize) -> bool {
+        matches!(self.validity, Some(validity) if !validity.get_bit_unchecked(idx))
+    }
+
+    /// Update the window position by setting the `start` index and the `end` index and
+    /// return the extremum of the window.
+    /// # Safety
+    /// The caller must ensure that `start` and `end` are within bounds of `self.slice` and
+    /// that they are not smaller than the previous `start` and `end`.
+    pub(super) unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        // values that leave the window
+        for idx in self.last_start..std::cmp::min(start, self.last_end) {
+            if self.is_null(idx) {
+                self.null_count -= 1;
+            }
+        }
+        while let Some(&idx) = self.deque.front() {
+            if idx >= start {
+                break;
+            }
+            self.deque.pop_front();
+        }
+
+        // values that enter the window
+        for idx in std::cmp::max(start, self.last_end)..end {
+            if self.is_null(idx) {
+                self.null_count += 1;
+                continue;
+            }
+            let value = self.slice.get_unchecked(idx);
+            // values that are not more extreme than the entering value can never be the
+            // extremum again
+            while let Some(&back) = self.deque.back() {
+                if (self.compare_fn_nan)(self.slice.get_unchecked(back), value) == self.agg_ordering
+                {
+                    break;
+                }
+                self.deque.pop_back();
+            }
+            self.deque.push_back(idx);
+        }
+
+        self.last_start = start;
+        self.last_end = end;
+        self.deque
+            .front()
+            .map(|&idx| *self.slice.get_unchecked(idx))
+    }
+
+    pub(super) fn window_len(&self) -> usize {
+        self.last_end - self.last_start
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
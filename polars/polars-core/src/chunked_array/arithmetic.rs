@@ -8,7 +8,7 @@ use arrow::compute::arithmetics::basic;
 use arrow::compute::arithmetics::decimal;
 use arrow::compute::arity_assign;
 use arrow::types::NativeType;
-use num::{Num, NumCast, ToPrimitive};
+use num::{Bounded, Num, NumCast, ToPrimitive, Zero};
 
 use crate::prelude::*;
 use crate::series::IsSorted;
@@ -532,6 +532,48 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Divide element-wise by `rhs` without panicking on a zero denominator. At the positions
+    /// where `rhs` is zero the output is `zero_value`, or null if `zero_value` is `None`; for
+    /// floats `Some(f64::INFINITY)` gives the IEEE behavior for positive numerators.
+    ///
+    /// The integer division `MIN / -1` overflows and is null as well. Returns an error if the
+    /// lengths don't match.
+    pub fn safe_div(&self, rhs: &Self, zero_value: Option<T::Native>) -> PolarsResult<Self> {
+        if self.len() != rhs.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot divide arrays of length {} and {}",
+                    self.len(),
+                    rhs.len()
+                )
+                .into(),
+            ));
+        }
+        let zero = T::Native::zero();
+        // `None` for unsigned integers
+        let minus_one: Option<T::Native> = NumCast::from(-1i8);
+        let overflows = |l: T::Native, r: T::Native| {
+            !T::Native::is_float() && Some(r) == minus_one && l == T::Native::min_value()
+        };
+        let mut out: Self = self
+            .into_iter()
+            .zip(rhs)
+            .map(|(opt_l, opt_r)| match (opt_l, opt_r) {
+                (Some(_), Some(r)) if r == zero => zero_value,
+                (Some(l), Some(r)) if overflows(l, r) => None,
+                (Some(l), Some(r)) => Some(l / r),
+                _ => None,
+            })
+            .collect_trusted();
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
 fn concat_strings(l: &str, r: &str) -> String {
     // fastest way to concat strings according to https://github.com/hoodie/concatenation_benchmarks-rs
     let mut s = String::with_capacity(l.len() + r.len());
@@ -703,4 +745,30 @@ pub(crate) mod test {
         let _ = &a1 / &a1;
         let _ = &a1 * &a1;
     }
+
+    #[test]
+    fn test_safe_div() -> PolarsResult<()> {
+        let a = Int32Chunked::new("a", &[Some(6), Some(1), None, Some(i32::MIN), Some(-4)]);
+        let b = Int32Chunked::new("b", &[Some(3), Some(0), Some(1), Some(-1), Some(2)]);
+        let out = a.safe_div(&b, None)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(2), None, None, None, Some(-2)]);
+        assert_eq!(
+            Vec::from(&a.safe_div(&b, Some(0))?),
+            &[Some(2), Some(0), None, None, Some(-2)]
+        );
+
+        let a = Float64Chunked::new("a", &[1.0, 1.0]);
+        let b = Float64Chunked::new("b", &[0.0, 4.0]);
+        assert_eq!(
+            Vec::from(&a.safe_div(&b, Some(f64::INFINITY))?),
+            &[Some(f64::INFINITY), Some(0.25)]
+        );
+        let a = UInt32Chunked::new("a", &[u32::MAX]);
+        assert_eq!(Vec::from(&a.safe_div(&a, None)?), &[Some(1)]);
+        assert!(a
+            .safe_div(&UInt32Chunked::new("b", &[1u32, 2]), None)
+            .is_err());
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use arrow::bitmap::Bitmap;
 use arrow::compute;
 
 use super::*;
@@ -255,6 +256,118 @@ impl BitAnd for BooleanChunked {
     }
 }
 
+/// Row-wise kleene `any` (or `all` if `any == false`) over multiple boolean columns.
+fn kleene_horizontal(cols: &[&BooleanChunked], any: bool) -> PolarsResult<BooleanChunked> {
+    let first = cols.first().ok_or_else(|| {
+        PolarsError::NoData("cannot do a horizontal aggregation over zero columns".into())
+    })?;
+    let len = first.len();
+    if cols.iter().any(|ca| ca.len() != len) {
+        return Err(PolarsError::ShapeMisMatch(
+            "columns in a horizontal aggregation should have equal lengths".into(),
+        ));
+    }
+
+    // rows that have a valid value that decides the outcome:
+    // `true` for `any` and `false` for `all`
+    let mut decided = Bitmap::new_zeroed(len);
+    // rows that have at least one null value
+    let mut has_null: Option<Bitmap> = None;
+
+    for ca in cols {
+        let ca = ca.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let values = if any {
+            arr.values().clone()
+        } else {
+            !arr.values()
+        };
+        match arr.validity() {
+            Some(validity) if validity.unset_bits() > 0 => {
+                decided = &decided | &(&values & validity);
+                let nulls = !validity;
+                has_null = Some(match has_null {
+                    Some(has_null) => &has_null | &nulls,
+                    None => nulls,
+                });
+            }
+            _ => decided = &decided | &values,
+        }
+    }
+
+    // a row is null if its outcome is not decided and it has a null value
+    let validity = has_null.map(|has_null| &decided | &!&has_null);
+    let values = if any { decided } else { !&decided };
+    let arr = BooleanArray::from_data_default(values, validity);
+    // safety: same type
+    unsafe {
+        Ok(BooleanChunked::from_chunks(
+            first.name(),
+            vec![Box::new(arr)],
+        ))
+    }
+}
+
+impl BooleanChunked {
+    /// Row-wise `any` over multiple boolean columns that follows kleene logic:
+    /// `any([true, null]) == true` and `any([false, null]) == null`.
+    pub fn any_horizontal(cols: &[&BooleanChunked]) -> PolarsResult<BooleanChunked> {
+        kleene_horizontal(cols, true)
+    }
+
+    /// Row-wise `all` over multiple boolean columns that follows kleene logic:
+    /// `all([false, null]) == false` and `all([true, null]) == null`.
+    pub fn all_horizontal(cols: &[&BooleanChunked]) -> PolarsResult<BooleanChunked> {
+        kleene_horizontal(cols, false)
+    }
+
+    /// Pack the values into bytes, most significant bit first. Null values are packed as
+    /// `false` and the last byte is padded with zeros.
+    pub fn pack_bits(&self) -> Vec<u8> {
+        pack_bits_msb(
+            self.into_iter().map(|opt_v| opt_v.unwrap_or(false)),
+            self.len(),
+        )
+    }
+
+    /// Pack the values with [`BooleanChunked::pack_bits`] and the validity in the same layout,
+    /// where a set bit means valid. The validity is `None` if there are no null values.
+    pub fn pack_bits_with_validity(&self) -> (Vec<u8>, Option<Vec<u8>>) {
+        let validity = self
+            .has_validity()
+            .then(|| pack_bits_msb(self.into_iter().map(|opt_v| opt_v.is_some()), self.len()));
+        (self.pack_bits(), validity)
+    }
+
+    /// Create a [`BooleanChunked`] of length `len` from bytes packed most significant bit
+    /// first, the inverse of [`BooleanChunked::pack_bits`].
+    ///
+    /// # Panics
+    /// Panics if `bytes` holds fewer than `len` bits.
+    pub fn from_packed_bits(bytes: &[u8], len: usize, name: &str) -> BooleanChunked {
+        assert!(
+            bytes.len() * 8 >= len,
+            "{} bytes cannot hold {len} bits",
+            bytes.len()
+        );
+        let mut ca: BooleanChunked = (0..len)
+            .map(|i| (bytes[i / 8] >> (7 - i % 8)) & 1 == 1)
+            .collect();
+        ca.rename(name);
+        ca
+    }
+}
+
+fn pack_bits_msb(iter: impl Iterator<Item = bool>, len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; (len + 7) / 8];
+    for (i, bit) in iter.enumerate() {
+        if bit {
+            out[i / 8] |= 1 << (7 - i % 8);
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -269,4 +382,65 @@ mod test {
         assert_eq!((&a).bitor(&b).null_count(), 1);
         assert_eq!((&a).bitxor(&b).null_count(), 1);
     }
+
+    #[test]
+    fn test_kleene_horizontal() -> PolarsResult<()> {
+        let a = BooleanChunked::new("a", [Some(true), Some(false), Some(false), None, None]);
+        let b = BooleanChunked::new("b", [None, None, Some(false), Some(true), None]);
+
+        let out = BooleanChunked::any_horizontal(&[&a, &b])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), None, Some(false), Some(true), None]
+        );
+
+        let out = BooleanChunked::all_horizontal(&[&a, &b])?;
+        assert_eq!(
+            Vec::from(&out),
+            &[None, Some(false), Some(false), None, None]
+        );
+
+        let c = BooleanChunked::new("c", [true, true, true, true, true]);
+        let out = BooleanChunked::all_horizontal(&[&c, &a])?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(false), None, None]
+        );
+
+        assert!(BooleanChunked::any_horizontal(&[]).is_err());
+        assert!(BooleanChunked::any_horizontal(&[&a, &a.slice(0, 2)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pack_bits() {
+        let ca = BooleanChunked::new(
+            "a",
+            [
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+            ],
+        );
+        let (values, validity) = ca.pack_bits_with_validity();
+        assert_eq!(values, &[0b1001_1000, 0b1100_0000]);
+        assert_eq!(validity.unwrap(), &[0b1101_1111, 0b1100_0000]);
+
+        let out = BooleanChunked::from_packed_bits(&values, ca.len(), "b");
+        assert_eq!(out.name(), "b");
+        assert_eq!(
+            Vec::from(&out),
+            Vec::from(&ca.fill_null_with_values(false).unwrap())
+        );
+        let ca = BooleanChunked::new("a", [true, false, true]);
+        assert_eq!(ca.pack_bits_with_validity(), (vec![0b1010_0000], None));
+    }
 }
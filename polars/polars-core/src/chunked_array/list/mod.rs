@@ -24,9 +24,73 @@ impl ListChunked {
         }
     }
 
+    /// Apply a fallible function on every inner `Series`. Null lists stay null and the
+    /// function may change the inner data type.
+    pub fn list_eval<F>(&self, f: F) -> PolarsResult<ListChunked>
+    where
+        F: Fn(Series) -> PolarsResult<Series>,
+    {
+        // the output dtype cannot be inferred without non-null lists
+        if self.null_count() == self.len() {
+            return Ok(self.clone());
+        }
+        let mut fast_explode = self.null_count() == 0;
+        let mut ca: ListChunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s
+                    .map(|s| {
+                        let out = f(s)?;
+                        if out.is_empty() {
+                            fast_explode = false;
+                        }
+                        Ok(out)
+                    })
+                    .transpose()
+            })
+            .collect::<PolarsResult<_>>()?;
+        ca.rename(self.name());
+        if fast_explode {
+            ca.set_fast_explode();
+        }
+        Ok(ca)
+    }
+
     pub fn to_logical(&mut self, inner_dtype: DataType) {
         assert_eq!(inner_dtype.to_physical(), self.inner_dtype());
         let fld = Arc::make_mut(&mut self.field);
         fld.coerce(DataType::List(Box::new(inner_dtype)))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::builder::get_list_builder;
+
+    #[test]
+    fn test_list_eval() -> PolarsResult<()> {
+        let mut builder = get_list_builder(&DataType::Int32, 10, 10, "a")?;
+        builder.append_series(&Series::new("", &[3, 1, 2]));
+        builder.append_null();
+        builder.append_series(&Series::new("", &[5, 4]));
+        let ca = builder.finish();
+
+        let out = ca.list_eval(|s| Ok(s.sort(false)))?;
+        assert_eq!(out.name(), "a");
+        let expected = [Some(vec![1, 2, 3]), None, Some(vec![4, 5])];
+        for (s, e) in out.into_iter().zip(expected) {
+            let s = s.map(|s| s.i32().unwrap().into_no_null_iter().collect::<Vec<_>>());
+            assert_eq!(s, e);
+        }
+
+        let out = ca.list_eval(|s| s.cast(&DataType::Float64))?;
+        assert_eq!(out.inner_dtype(), DataType::Float64);
+        assert_eq!(out.null_count(), 1);
+
+        assert!(ca
+            .list_eval(|_| Err(PolarsError::ComputeError("oops".into())))
+            .is_err());
+        Ok(())
+    }
+}
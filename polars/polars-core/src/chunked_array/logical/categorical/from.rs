@@ -1,4 +1,4 @@
-use arrow::array::DictionaryArray;
+use arrow::array::{DictionaryArray, DictionaryKey};
 use arrow::datatypes::IntegerType;
 use polars_arrow::compute::cast::cast;
 
@@ -104,3 +104,79 @@ impl CategoricalChunked {
         }
     }
 }
+
+impl CategoricalChunked {
+    /// Convert to an arrow [`DictionaryArray`] with the narrowest unsigned key type that can
+    /// index the categories: `u8` for fewer than 256 categories, `u16` for fewer than 65536
+    /// and `u32` otherwise.
+    pub fn to_arrow_compact(&self) -> ArrayRef {
+        let arr = DictionaryArray::<u32>::from(self);
+        let n_categories = arr.values().len();
+        if n_categories < (u8::MAX as usize + 1) {
+            narrow_keys::<u8>(&arr)
+        } else if n_categories < (u16::MAX as usize + 1) {
+            narrow_keys::<u16>(&arr)
+        } else {
+            Box::new(arr)
+        }
+    }
+}
+
+fn narrow_keys<K: DictionaryKey>(arr: &DictionaryArray<u32>) -> ArrayRef {
+    let keys = cast(arr.keys(), &K::KEY_TYPE.into()).unwrap();
+    let keys = keys.as_any().downcast_ref::<PrimitiveArray<K>>().unwrap();
+    let dtype = ArrowDataType::Dictionary(
+        K::KEY_TYPE,
+        Box::new(arr.values().data_type().clone()),
+        false,
+    );
+    // Safety:
+    // the keys are in bounds
+    unsafe {
+        Box::new(
+            DictionaryArray::try_new_unchecked(dtype, keys.clone(), arr.values().clone()).unwrap(),
+        )
+    }
+}
+
+impl Utf8Chunked {
+    /// Dictionary encode the strings: every unique string becomes a category and every value
+    /// is replaced by the index of its category.
+    ///
+    /// The categories are indexed by `u32` like every [`CategoricalChunked`], use
+    /// [`CategoricalChunked::to_arrow_compact`] to get an arrow dictionary with narrower keys.
+    pub fn into_dictionary(&self) -> PolarsResult<CategoricalChunked> {
+        let s = self.cast(&DataType::Categorical(None))?;
+        Ok(s.categorical()?.clone())
+    }
+
+    /// Decode the indices of a [`CategoricalChunked`] to their string values, the reverse of
+    /// [`Utf8Chunked::into_dictionary`].
+    ///
+    /// Returns an error if `ca` has no categorical map or if an index is not in the map.
+    pub fn from_categorical_indices(ca: &CategoricalChunked) -> PolarsResult<Self> {
+        let rev_map = match ca.dtype() {
+            DataType::Categorical(Some(rev_map)) => rev_map,
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    "cannot decode a categorical without a categorical map".into(),
+                ))
+            }
+        };
+        let mut builder = Utf8ChunkedBuilder::new(ca.name(), ca.len(), ca.len() * 5);
+        for opt_idx in ca.logical() {
+            match opt_idx {
+                Some(idx) => {
+                    let s = rev_map.get_optional(idx).ok_or_else(|| {
+                        PolarsError::ComputeError(
+                            format!("categorical index {idx} is not in the categorical map").into(),
+                        )
+                    })?;
+                    builder.append_value(s)
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
@@ -220,9 +220,49 @@ impl<'a> ExactSizeIterator for CatIter<'a> {}
 mod test {
     use std::convert::TryFrom;
 
+    use arrow::datatypes::IntegerType;
+
     use super::*;
     use crate::{reset_string_cache, toggle_string_cache, SINGLE_LOCK};
 
+    #[test]
+    fn test_into_dictionary() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        let ca = Utf8Chunked::new("a", &[Some("foo"), None, Some("bar"), Some("foo")]);
+        let cat = ca.into_dictionary()?;
+        assert_eq!(cat.name(), "a");
+        assert_eq!(cat.get_rev_map().len(), 2);
+        assert_eq!(
+            cat.iter_str().collect::<Vec<_>>(),
+            &[Some("foo"), None, Some("bar"), Some("foo")]
+        );
+
+        let arr = cat.to_arrow_compact();
+        assert!(matches!(
+            arr.data_type(),
+            ArrowDataType::Dictionary(IntegerType::UInt8, _, _)
+        ));
+        let s = Series::try_from(("a", arr))?;
+        assert_eq!(Vec::from(s.cast(&DataType::Utf8)?.utf8()?), Vec::from(&ca));
+
+        let decoded = Utf8Chunked::from_categorical_indices(&cat)?;
+        assert_eq!(decoded.name(), "a");
+        assert_eq!(Vec::from(&decoded), Vec::from(&ca));
+        let s = cat.into_series().decode_categorical()?;
+        assert_eq!(Vec::from(s.utf8()?), Vec::from(&ca));
+        assert!(ca.into_series().decode_categorical().is_err());
+
+        let values = (0..300).map(|i| i.to_string()).collect::<Vec<_>>();
+        let ca = Utf8Chunked::from_iter_values("a", values.iter().map(|s| s.as_str()));
+        let arr = ca.into_dictionary()?.to_arrow_compact();
+        assert!(matches!(
+            arr.data_type(),
+            ArrowDataType::Dictionary(IntegerType::UInt16, _, _)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_categorical_round_trip() -> PolarsResult<()> {
         let _lock = SINGLE_LOCK.lock();
@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use arrow::array::*;
-use arrow::bitmap::Bitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_arrow::prelude::ValueSize;
 
 use crate::prelude::*;
@@ -31,6 +31,7 @@ pub(crate) mod logical;
 pub mod object;
 #[cfg(feature = "random")]
 mod random;
+mod raw_parts;
 #[cfg(any(
     feature = "temporal",
     feature = "dtype-datetime",
@@ -45,6 +46,7 @@ use std::slice::Iter;
 
 use bitflags::bitflags;
 use polars_arrow::prelude::*;
+pub use raw_parts::ReleaseCallback;
 
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, last_non_null, CustomIterTools};
@@ -54,6 +56,30 @@ pub struct RevMapping {}
 
 pub type ChunkIdIter<'a> = std::iter::Map<std::slice::Iter<'a, ArrayRef>, fn(&ArrayRef) -> usize>;
 
+/// The validity of a [`ChunkedArray`], see [`ChunkedArray::validity_mask`].
+#[derive(Clone)]
+pub enum ValidityMask {
+    /// None of the given number of values is null. No mask is materialized.
+    AllValid(usize),
+    /// `true` for valid and `false` for null values.
+    Mask(BooleanChunked),
+}
+
+impl ValidityMask {
+    /// Whether none of the values is null.
+    pub fn is_all_valid(&self) -> bool {
+        matches!(self, ValidityMask::AllValid(_))
+    }
+
+    /// Get the mask as a [`BooleanChunked`]. This materializes the mask if all values are valid.
+    pub fn into_mask(self, name: &str) -> BooleanChunked {
+        match self {
+            ValidityMask::AllValid(len) => BooleanChunked::full(name, true, len),
+            ValidityMask::Mask(mask) => mask,
+        }
+    }
+}
+
 /// # ChunkedArray
 ///
 /// Every Series contains a `ChunkedArray<T>`. Unlike Series, ChunkedArray's are typed. This allows
@@ -372,6 +398,76 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         unsafe { BooleanChunked::from_chunks(self.name(), chunks) }
     }
 
+    /// Get the validity of the values: `true` for valid and `false` for null values.
+    ///
+    /// If there are no nulls, [`ValidityMask::AllValid`] is returned without building a mask,
+    /// so callers can take a no-null fast path. Otherwise the validity bitmaps of the chunks
+    /// are reused without copying; only chunks that have no bitmap are filled with `true`.
+    pub fn validity_mask(&self) -> ValidityMask {
+        if self.null_count() == 0 {
+            return ValidityMask::AllValid(self.len());
+        }
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|arr| {
+                let bitmap = match arr.validity() {
+                    Some(bitmap) => bitmap.clone(),
+                    None => {
+                        let mut bitmap = MutableBitmap::with_capacity(arr.len());
+                        bitmap.extend_constant(arr.len(), true);
+                        bitmap.into()
+                    }
+                };
+                Box::new(BooleanArray::from_data_default(bitmap, None)) as ArrayRef
+            })
+            .collect::<Vec<_>>();
+        ValidityMask::Mask(unsafe { BooleanChunked::from_chunks(self.name(), chunks) })
+    }
+
+    /// Get a new array with the same values but the null bitmap replaced. A `true` in
+    /// `validity` marks a valid value, `false` and null mark a null. If `validity` is `None`,
+    /// all values are valid.
+    ///
+    /// Returns an error if the length of `validity` does not match.
+    pub fn set_validity(&self, validity: Option<BooleanChunked>) -> PolarsResult<Self> {
+        let chunks = match validity {
+            None => self
+                .chunks
+                .iter()
+                .map(|arr| arr.with_validity(None))
+                .collect(),
+            Some(validity) => {
+                if validity.len() != self.len() {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "validity with length {} does not match array with length {}",
+                            validity.len(),
+                            self.len()
+                        )
+                        .into(),
+                    ));
+                }
+                let validity = validity.rechunk();
+                let arr = validity.downcast_iter().next().unwrap();
+                let bitmap = match arr.validity() {
+                    Some(nulls) => arr.values() & nulls,
+                    None => arr.values().clone(),
+                };
+                let mut offset = 0;
+                self.chunks
+                    .iter()
+                    .map(|arr| {
+                        let chunk_validity = bitmap.clone().slice(offset, arr.len());
+                        offset += arr.len();
+                        arr.with_validity(Some(chunk_validity))
+                    })
+                    .collect()
+            }
+        };
+        Ok(self.copy_with_chunks(chunks, false))
+    }
+
     pub(crate) fn coalesce_nulls(&self, other: &[ArrayRef]) -> Self {
         assert_eq!(self.chunks.len(), other.len());
         let chunks = self
@@ -412,6 +508,25 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     pub fn rename(&mut self, name: &str) {
         self.field = Arc::new(Field::new(name, self.field.data_type().clone()))
     }
+
+    /// Rename this ChunkedArray. Unlike [`ChunkedArray::rename`], this does not allocate
+    /// if the name doesn't change, in which case `self` is returned unchanged.
+    #[must_use]
+    pub fn with_name(mut self, name: &str) -> Self {
+        if self.name() != name {
+            self.rename(name);
+        }
+        self
+    }
+
+    /// Rename this ChunkedArray by mutating its field in place. The field is only cloned
+    /// if it is shared with another ChunkedArray, and nothing happens if the name doesn't
+    /// change.
+    pub fn rename_in_place(&mut self, name: &str) {
+        if self.name() != name {
+            Arc::make_mut(&mut self.field).set_name(name.to_string());
+        }
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -629,6 +744,42 @@ pub(crate) mod test {
         assert_eq!(a.is_sorted_flag(), true);
     }
 
+    #[test]
+    fn validity_mask() {
+        let mut ca = Int32Chunked::new("a", &[1, 2]);
+        let mask = ca.validity_mask();
+        assert!(matches!(mask, ValidityMask::AllValid(2)));
+        assert_eq!(Vec::from(&mask.into_mask("a")), &[Some(true), Some(true)]);
+
+        ca.append(&Int32Chunked::new("a", &[None, Some(4)]));
+        let mask = ca.validity_mask();
+        assert!(!mask.is_all_valid());
+        let mask = mask.into_mask("a");
+        assert_eq!(mask.chunks().len(), 2);
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(true), Some(true), Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn set_validity() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a", &[Some(1), None]);
+        ca.append(&Int32Chunked::new("a", &[3, 4]));
+        let validity = BooleanChunked::new("", &[Some(true), Some(true), None, Some(false)]);
+        let out = ca.set_validity(Some(validity))?;
+        assert_eq!(out.chunks().len(), 2);
+        assert_eq!(Vec::from(&out)[2..], [None, None]);
+        assert_eq!(out.get(0), Some(1));
+
+        let out = ca.set_validity(None)?;
+        assert_eq!(out.null_count(), 0);
+        assert!(ca
+            .set_validity(Some(BooleanChunked::full("", true, 3)))
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn arithmetic() {
         let a = &Int32Chunked::new("a", &[1, 100, 6, 40]);
@@ -776,6 +927,23 @@ pub(crate) mod test {
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
 
+    #[test]
+    fn test_with_name() {
+        let ca = Int32Chunked::new("a", &[1, 2]);
+        let field = ca.field.clone();
+        let ca = ca.with_name("a");
+        assert!(Arc::ptr_eq(&field, &ca.field));
+        let mut ca = ca.with_name("b");
+        assert_eq!(ca.name(), "b");
+        assert_eq!(field.name(), "a");
+
+        drop(field);
+        let ptr = Arc::as_ptr(&ca.field);
+        ca.rename_in_place("c");
+        assert_eq!(ca.name(), "c");
+        assert_eq!(ptr, Arc::as_ptr(&ca.field));
+    }
+
     #[test]
     #[ignore]
     fn test_shrink_to_fit() {
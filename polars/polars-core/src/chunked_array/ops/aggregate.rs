@@ -487,6 +487,60 @@ impl ChunkQuantile<f64> for Float64Chunked {
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Compute the quantiles `qs` of the non-null values, sorting the values only once.
+    ///
+    /// The results are in the order of `qs` and equal those of calling
+    /// [`ChunkQuantile::quantile`] for every `q`. Returns an error if any `q` is outside
+    /// `[0.0, 1.0]`.
+    pub fn quantiles(
+        &self,
+        qs: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Vec<Option<f64>>> {
+        if qs.iter().any(|q| !(0.0..=1.0).contains(q)) {
+            return Err(PolarsError::ComputeError(
+                "quantile should be between 0.0 and 1.0".into(),
+            ));
+        }
+        let mut vals = self
+            .into_iter()
+            .flatten()
+            .map(|v| v.to_f64().unwrap())
+            .collect::<Vec<_>>();
+        if vals.is_empty() {
+            return Ok(vec![None; qs.len()]);
+        }
+        vals.sort_unstable_by(compare_fn_nan_max);
+
+        let out = qs
+            .iter()
+            .map(|&q| {
+                let (idx, float_idx, top_idx) = quantile_idx(q, vals.len(), 0, interpol);
+                let lower = vals[idx as usize];
+                if top_idx == idx {
+                    return Some(lower);
+                }
+                match interpol {
+                    QuantileInterpolOptions::Midpoint => {
+                        Some((lower + vals[idx as usize + 1]) / 2.0)
+                    }
+                    QuantileInterpolOptions::Linear => linear_interpol(
+                        &[Some(lower), Some(vals[idx as usize + 1])],
+                        idx,
+                        float_idx,
+                    ),
+                    _ => Some(lower),
+                }
+            })
+            .collect();
+        Ok(out)
+    }
+}
+
 impl ChunkQuantile<String> for Utf8Chunked {}
 impl ChunkQuantile<Series> for ListChunked {}
 #[cfg(feature = "object")]
@@ -574,6 +628,26 @@ impl ChunkVar<f64> for Float64Chunked {
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    /// Compute the variance with `ddof` delta degrees of freedom: `ddof = 0` gives the
+    /// population variance and `ddof = 1` the sample variance. The null values are ignored.
+    ///
+    /// Returns `None` if there are fewer than `ddof` non-null values, see [`ChunkVar::var`].
+    pub fn var_with_ddof(&self, ddof: u8) -> Option<f64> {
+        self.clone().into_series().var(ddof)
+    }
+
+    /// Compute the standard deviation with `ddof` delta degrees of freedom, see
+    /// [`ChunkedArray::var_with_ddof`].
+    pub fn std_with_ddof(&self, ddof: u8) -> Option<f64> {
+        self.var_with_ddof(ddof).map(|var| var.sqrt())
+    }
+}
+
 impl ChunkVar<String> for Utf8Chunked {}
 impl ChunkVar<Series> for ListChunked {}
 #[cfg(feature = "object")]
@@ -937,6 +1011,44 @@ mod test {
 
     use crate::prelude::*;
 
+    #[test]
+    fn test_quantiles() -> PolarsResult<()> {
+        let ca = Int32Chunked::new(
+            "",
+            &[Some(5), None, Some(1), Some(3), Some(2), Some(4), None],
+        );
+        let qs = [0.0, 0.1, 0.25, 0.5, 0.9, 1.0];
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            let out = ca.quantiles(&qs, interpol)?;
+            let expected = qs
+                .iter()
+                .map(|q| ca.quantile(*q, interpol))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            assert_eq!(out, expected);
+        }
+
+        let ca = Float64Chunked::new("", &[0.5, 1.5]);
+        assert_eq!(
+            ca.quantiles(&[0.5, 0.0], QuantileInterpolOptions::Linear)?,
+            &[Some(1.0), Some(0.5)]
+        );
+        let ca = Int32Chunked::new("", &[None, None]);
+        assert_eq!(
+            ca.quantiles(&[0.5], QuantileInterpolOptions::Linear)?,
+            &[None]
+        );
+        assert!(ca
+            .quantiles(&[0.5, 1.5], QuantileInterpolOptions::Linear)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_var() {
         // validated with numpy
@@ -964,6 +1076,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_var_with_ddof() {
+        let ca = Int32Chunked::new("", &[Some(5), None, Some(8), Some(9), Some(5), Some(0)]);
+        assert_eq!(ca.var_with_ddof(0), Some(9.84));
+        assert_eq!(ca.var_with_ddof(1), Some(12.3));
+        assert!((ca.std_with_ddof(0).unwrap() - 9.84f64.sqrt()).abs() < 1e-12);
+        let ca = Float32Chunked::new("", &[Some(1.0), None]);
+        assert_eq!(ca.var_with_ddof(0), Some(0.0));
+        assert_eq!(ca.var_with_ddof(2), None);
+        assert_eq!(ca.into_series().var(2), None);
+        let s = Series::new("", &[1.0, 3.0]);
+        assert_eq!(s.var(0), Some(1.0));
+        assert_eq!(s.std(1), Some(2f64.sqrt()));
+    }
+
     #[test]
     fn test_agg_float() {
         let ca1 = Float32Chunked::new("a", &[1.0, f32::NAN]);
@@ -5,10 +5,12 @@ use std::convert::TryFrom;
 use arrow::array::{BooleanArray, PrimitiveArray};
 use polars_arrow::array::PolarsArray;
 use polars_arrow::trusted_len::PushUnchecked;
+use rayon::prelude::*;
 
 use crate::prelude::*;
 use crate::series::IsSorted;
-use crate::utils::{CustomIterTools, NoNull};
+use crate::utils::{CustomIterTools, NoNull, _split_offsets};
+use crate::POOL;
 
 macro_rules! try_apply {
     ($self:expr, $f:expr) => {{
@@ -124,6 +126,22 @@ impl<T: PolarsNumericType> ChunkedArray<T> {
     }
 }
 
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Map every element to zero or more values and flatten the results into a single array.
+    ///
+    /// Unlike `apply`, the output can have a different length and data type.
+    pub fn flat_map<F, I, B>(&self, f: F) -> ChunkedArray<B>
+    where
+        F: Fn(Option<T::Native>) -> I,
+        I: IntoIterator<Item = Option<B::Native>>,
+        B: PolarsNumericType,
+    {
+        let mut ca: ChunkedArray<B> = self.into_iter().flat_map(f).collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 impl<'a, T> ChunkApply<'a, T::Native, T::Native> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -861,3 +879,59 @@ where
         });
     }
 }
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Apply a closure elementwise, including null values, like
+    /// [`ChunkApply::apply_on_opt`], but in parallel. The array is split into one slice per
+    /// thread and the closure is called independently on every chunk of those slices, so
+    /// the output has at least one chunk per thread.
+    pub fn par_apply<F>(&self, f: F) -> Self
+    where
+        F: Fn(Option<T::Native>) -> Option<T::Native> + Send + Sync,
+    {
+        let n_threads = POOL.current_num_threads();
+        if n_threads == 1 || self.len() < n_threads {
+            return self.apply_on_opt(&f);
+        }
+        let offsets = _split_offsets(self.len(), n_threads);
+        let chunks = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| self.slice(offset as i64, len).apply_on_opt(&f).chunks)
+                .collect::<Vec<_>>()
+        });
+        self.copy_with_chunks(chunks.into_iter().flatten().collect(), false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_flat_map() {
+        let ca = Int32Chunked::new("a", &[Some(2), None, Some(0), Some(1)]);
+        let out: Float64Chunked = ca.flat_map(|opt_v| match opt_v {
+            Some(v) => vec![Some(v as f64); v as usize],
+            None => vec![None],
+        });
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(2.0), Some(2.0), None, Some(1.0)]);
+    }
+
+    #[test]
+    fn test_par_apply() {
+        let values = (0..10_000)
+            .map(|v| (v % 7 != 0).then_some(v))
+            .collect::<Vec<_>>();
+        let ca = Int64Chunked::new("a", &values);
+        let f = |opt_v: Option<i64>| match opt_v {
+            Some(v) if v % 3 == 0 => None,
+            Some(v) => Some(v * v),
+            None => Some(-1),
+        };
+        let out = ca.par_apply(f);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), Vec::from(&ca.apply_on_opt(f)));
+    }
+}
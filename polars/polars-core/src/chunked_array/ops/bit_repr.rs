@@ -237,6 +237,15 @@ impl Reinterpret for Int32Chunked {
 }
 
 impl UInt64Chunked {
+    /// Reinterpret the values as the bits of `f64` without copying the buffers.
+    ///
+    /// # Safety
+    /// Any bit pattern is accepted, so the output may contain `NaN` payloads and
+    /// infinities. The caller must ensure those are acceptable downstream.
+    pub unsafe fn bit_repr_as_f64(&self) -> Float64Chunked {
+        self._reinterpret_float()
+    }
+
     #[doc(hidden)]
     pub fn _reinterpret_float(&self) -> Float64Chunked {
         let chunks = self
@@ -266,6 +275,15 @@ impl UInt64Chunked {
     }
 }
 impl UInt32Chunked {
+    /// Reinterpret the values as the bits of `f32` without copying the buffers.
+    ///
+    /// # Safety
+    /// Any bit pattern is accepted, so the output may contain `NaN` payloads and
+    /// infinities. The caller must ensure those are acceptable downstream.
+    pub unsafe fn bit_repr_as_f32(&self) -> Float32Chunked {
+        self._reinterpret_float()
+    }
+
     #[doc(hidden)]
     pub fn _reinterpret_float(&self) -> Float32Chunked {
         let chunks = self
@@ -298,6 +316,15 @@ impl UInt32Chunked {
 /// Used to save compilation paths. Use carefully. Although this is safe,
 /// if misused it can lead to incorrect results.
 impl Float32Chunked {
+    /// Reinterpret the bits of the values as `u32` without copying the buffers.
+    ///
+    /// # Safety
+    /// The bits are preserved as is, so `NaN` payloads and infinities map to integers that
+    /// don't order or compare as the floats do. The caller must not rely on numeric semantics.
+    pub unsafe fn bit_repr_as_u32(&self) -> UInt32Chunked {
+        self.bit_repr_small()
+    }
+
     pub(crate) fn apply_as_ints<F>(&self, f: F) -> Series
     where
         F: Fn(&Series) -> Series,
@@ -309,6 +336,15 @@ impl Float32Chunked {
     }
 }
 impl Float64Chunked {
+    /// Reinterpret the bits of the values as `u64` without copying the buffers.
+    ///
+    /// # Safety
+    /// The bits are preserved as is, so `NaN` payloads and infinities map to integers that
+    /// don't order or compare as the floats do. The caller must not rely on numeric semantics.
+    pub unsafe fn bit_repr_as_u64(&self) -> UInt64Chunked {
+        self.bit_repr_large()
+    }
+
     pub(crate) fn apply_as_ints<F>(&self, f: F) -> Series
     where
         F: Fn(&Series) -> Series,
@@ -319,3 +355,30 @@ impl Float64Chunked {
         out._reinterpret_float().into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bit_repr_round_trip() {
+        let ca = Float64Chunked::new("a", &[Some(1.5), None, Some(f64::NEG_INFINITY)]);
+        let bits = unsafe { ca.bit_repr_as_u64() };
+        assert_eq!(bits.get(0), Some(1.5f64.to_bits()));
+        assert_eq!(bits.null_count(), 1);
+        // zero copy
+        assert_eq!(
+            bits.downcast_iter().next().unwrap().values().as_ptr() as usize,
+            ca.downcast_iter().next().unwrap().values().as_ptr() as usize
+        );
+        let back = unsafe { bits.bit_repr_as_f64() };
+        assert_eq!(Vec::from(&back), Vec::from(&ca));
+
+        let ca = Float32Chunked::new("a", &[f32::NAN, 2.0]);
+        let bits = unsafe { ca.bit_repr_as_u32() };
+        assert_eq!(bits.get(0), Some(f32::NAN.to_bits()));
+        let back = unsafe { bits.bit_repr_as_f32() };
+        assert!(back.get(0).unwrap().is_nan());
+        assert_eq!(back.get(1), Some(2.0));
+    }
+}
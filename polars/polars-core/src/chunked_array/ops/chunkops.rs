@@ -109,6 +109,24 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         }
     }
 
+    /// Repeat the values of this array `n` times, producing an array of length `self.len() * n`.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> Self {
+        match n {
+            0 => self.slice(0, 0),
+            1 => self.clone(),
+            _ => {
+                let chunks = (0..n).flat_map(|_| self.chunks.iter().cloned()).collect();
+                let out = self.copy_with_chunks(chunks, false);
+                match self.dtype() {
+                    #[cfg(feature = "object")]
+                    DataType::Object(_) => out,
+                    _ => out.rechunk(),
+                }
+            }
+        }
+    }
+
     /// Slice the array. The chunks are reallocated the underlying data slices are zero copy.
     ///
     /// When offset is negative it will be counted from the end of the array.
@@ -155,6 +173,89 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         };
         self.slice(-(len as i64), len)
     }
+
+    /// Concatenate the slices `self[start..end]` for every `(start, end)` in `ranges`.
+    ///
+    /// The ranges don't have to be sorted, but they must be in bounds and may not overlap.
+    pub fn take_ranges(&self, ranges: &[(usize, usize)]) -> PolarsResult<Self> {
+        let mut sorted = ranges.to_vec();
+        sorted.sort_unstable();
+        for &(start, end) in &sorted {
+            if start > end || end > self.len() {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "range {start}..{end} is invalid for an array of length {}",
+                        self.len()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        // empty ranges cannot overlap, but they could separate two ranges that do
+        sorted.retain(|(start, end)| start < end);
+        for w in sorted.windows(2) {
+            let ((start, end), (next_start, next_end)) = (w[0], w[1]);
+            if next_start < end {
+                return Err(PolarsError::ComputeError(
+                    format!("ranges {start}..{end} and {next_start}..{next_end} overlap").into(),
+                ));
+            }
+        }
+
+        let chunks = ranges
+            .iter()
+            .filter(|(start, end)| start < end)
+            .flat_map(|&(start, end)| slice(&self.chunks, start as i64, end - start, self.len()).0)
+            .collect::<Vec<_>>();
+        if chunks.is_empty() {
+            return Ok(self.slice(0, 0));
+        }
+        let out = self.copy_with_chunks(chunks, false);
+        Ok(match self.dtype() {
+            #[cfg(feature = "object")]
+            DataType::Object(_) => out,
+            _ => out.rechunk(),
+        })
+    }
+}
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Take a view of the longest prefix for which `pred` returns `true`, like
+    /// [`Iterator::take_while`].
+    #[must_use]
+    pub fn take_while<F>(&self, pred: F) -> Self
+    where
+        F: Fn(Option<T::Native>) -> bool,
+    {
+        let len = self.into_iter().take_while(|v| pred(*v)).count();
+        self.slice(0, len)
+    }
+
+    /// Take a view of the elements after the longest prefix for which `pred` returns `true`,
+    /// like [`Iterator::skip_while`].
+    #[must_use]
+    pub fn skip_while<F>(&self, pred: F) -> Self
+    where
+        F: Fn(Option<T::Native>) -> bool,
+    {
+        let offset = self.into_iter().take_while(|v| pred(*v)).count();
+        self.slice(offset as i64, self.len() - offset)
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: IntoSeries,
+{
+    /// Split into one single chunk [`Series`] per chunk, e.g. to process the chunks on
+    /// different threads. Every [`Series`] has the name of `self` and the data is not copied;
+    /// concatenating them restores `self`.
+    pub fn into_chunk_series(self) -> Vec<Series> {
+        self.chunks
+            .iter()
+            .map(|arr| self.copy_with_chunks(vec![arr.clone()], true).into_series())
+            .collect()
+    }
 }
 
 #[cfg(feature = "object")]
@@ -192,7 +293,6 @@ impl<T: PolarsObject> ObjectChunked<T> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
     use crate::prelude::*;
 
     #[test]
@@ -205,4 +305,55 @@ mod test {
         let a = a.rechunk();
         assert!(a.categorical().unwrap().get_rev_map().len() > 0);
     }
+
+    #[test]
+    fn test_take_ranges() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a", &[0, 1, 2, 3]);
+        ca.append(&Int32Chunked::new("a", &[4, 5, 6]));
+        let out = ca.take_ranges(&[(5, 7), (2, 5), (0, 0), (0, 1)])?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(5), Some(6), Some(2), Some(3), Some(4), Some(0)]
+        );
+        assert_eq!(out.chunks().len(), 1);
+
+        assert!(ca.take_ranges(&[(0, 8)]).is_err());
+        assert!(ca.take_ranges(&[(3, 2)]).is_err());
+        assert!(ca.take_ranges(&[(4, 6), (0, 5)]).is_err());
+        // an empty range between two overlapping ranges doesn't hide the overlap
+        assert!(ca.take_ranges(&[(0, 7), (3, 3), (5, 6)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_skip_while() {
+        let mut ca = Int32Chunked::new("a", &[Some(1), Some(2), None]);
+        ca.append(&Int32Chunked::new("a", &[Some(4), Some(1)]));
+        let below_3 = |v: Option<i32>| v.map_or(true, |v| v < 3);
+
+        let out = ca.take_while(below_3);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(1), Some(2), None]);
+        assert_eq!(Vec::from(&ca.skip_while(below_3)), &[Some(4), Some(1)]);
+
+        assert!(ca.take_while(|_| false).is_empty());
+        assert_eq!(ca.skip_while(|_| false).len(), 5);
+        assert_eq!(ca.take_while(|_| true).len(), 5);
+        assert!(ca.skip_while(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_into_chunk_series() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a", &[Some(0), None]);
+        ca.append(&Int32Chunked::new("a", &[2, 3, 4]));
+        let out = ca.clone().into_chunk_series();
+        assert_eq!(out.len(), 2);
+        assert!(out.iter().all(|s| s.name() == "a" && s.n_chunks() == 1));
+        assert_eq!(out[1].len(), 3);
+
+        let mut concatenated = out[0].clone();
+        concatenated.append(&out[1])?;
+        assert!(concatenated.series_equal_missing(&ca.into_series()));
+        Ok(())
+    }
 }
@@ -0,0 +1,111 @@
+use arrow::array::ListArray;
+use arrow::offset::Offsets;
+
+use crate::prelude::*;
+
+/// Number of `k`-combinations of `n` elements, `None` on overflow.
+fn n_combinations(n: usize, k: usize) -> Option<usize> {
+    let k = std::cmp::min(k, n - k);
+    (0..k).try_fold(1usize, |acc, i| acc.checked_mul(n - i).map(|v| v / (i + 1)))
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTake + IntoSeries,
+{
+    /// Get every combination of `k` values, in lexicographic order of their indices.
+    ///
+    /// Returns an error if `k > self.len()` or if there are more than `max_combinations`
+    /// combinations.
+    pub fn combinations(&self, k: usize, max_combinations: usize) -> PolarsResult<ListChunked> {
+        let n = self.len();
+        if k > n {
+            return Err(PolarsError::ComputeError(
+                format!("cannot take combinations of {k} values from an array of length {n}")
+                    .into(),
+            ));
+        }
+        let n_out = n_combinations(n, k)
+            .filter(|n_out| *n_out <= max_combinations)
+            .ok_or_else(|| {
+                PolarsError::ComputeError(
+                    format!("the number of combinations exceeds the maximum of {max_combinations}")
+                        .into(),
+                )
+            })?;
+
+        let mut idx = Vec::with_capacity(n_out * k);
+        let mut current = (0..k as IdxSize).collect::<Vec<_>>();
+        for _ in 0..n_out {
+            idx.extend_from_slice(&current);
+            // advance to the next combination
+            if let Some(i) = (0..k).rev().find(|&i| (current[i] as usize) < n - k + i) {
+                current[i] += 1;
+                for j in i + 1..k {
+                    current[j] = current[j - 1] + 1;
+                }
+            }
+        }
+        let idx = IdxCa::from_vec("", idx);
+        // Safety: the indices are in bounds
+        let values = unsafe { self.take_unchecked((&idx).into()) }
+            .into_series()
+            .rechunk();
+        let values = values.array_ref(0).clone();
+
+        let offsets = (0..=n_out).map(|i| (i * k) as i64).collect::<Vec<_>>();
+        let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+        // Safety: offsets are monotonically increasing
+        let arr = ListArray::<i64>::new(
+            data_type,
+            unsafe { Offsets::new_unchecked(offsets).into() },
+            values,
+            None,
+        );
+        let mut ca = unsafe { ListChunked::from_chunks(self.name(), vec![Box::new(arr)]) };
+        if k > 0 {
+            ca.set_fast_explode()
+        }
+        Ok(ca)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_combinations() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(3), Some(4)]);
+        let out = ca.combinations(2, 10)?;
+        assert_eq!(out.len(), 6);
+        let out = out.explode()?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(1),
+                None,
+                Some(1),
+                Some(3),
+                Some(1),
+                Some(4),
+                None,
+                Some(3),
+                None,
+                Some(4),
+                Some(3),
+                Some(4)
+            ]
+        );
+
+        let ca = Utf8Chunked::new("a", &["a", "b", "c"]);
+        let out = ca.combinations(3, 1)?;
+        assert_eq!(out.len(), 1);
+        assert_eq!(out.get(0).unwrap().len(), 3);
+
+        assert_eq!(ca.combinations(0, 1)?.len(), 1);
+        assert!(ca.combinations(4, 10).is_err());
+        assert!(ca.combinations(2, 2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use rayon::prelude::*;
+
+use crate::prelude::*;
+use crate::POOL;
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Count the values for which `pred` returns `true`, without materializing a mask or the
+    /// filtered array. `pred` receives `None` for the null values. If there are multiple
+    /// chunks, they are counted in parallel.
+    pub fn count_where<F>(&self, pred: F) -> u64
+    where
+        F: Fn(Option<T::Native>) -> bool + Sync,
+    {
+        let count_chunk = |arr: &PrimitiveArray<T::Native>| {
+            arr.iter().filter(|opt_v| pred(opt_v.copied())).count() as u64
+        };
+        if self.chunks.len() == 1 {
+            count_chunk(self.downcast_iter().next().unwrap())
+        } else {
+            let chunks = self.downcast_iter().collect::<Vec<_>>();
+            POOL.install(|| chunks.into_par_iter().map(count_chunk).sum())
+        }
+    }
+
+    /// The number of null values, see [`ChunkedArray::null_count`].
+    pub fn count_null(&self) -> u64 {
+        self.null_count() as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_where() {
+        let mut ca = Int32Chunked::new("a", &[Some(1), None, Some(3)]);
+        assert_eq!(ca.count_where(|opt_v| opt_v.map_or(false, |v| v > 1)), 1);
+        ca.append(&Int32Chunked::new("a", &[Some(4), None]));
+        assert_eq!(ca.count_where(|opt_v| opt_v.map_or(false, |v| v > 1)), 2);
+        assert_eq!(ca.count_where(|opt_v| opt_v.is_none()), ca.count_null());
+        assert_eq!(ca.count_null(), 2);
+    }
+}
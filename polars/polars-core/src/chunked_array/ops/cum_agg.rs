@@ -137,6 +137,70 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: FromIterator<Option<T::Native>>,
+{
+    fn cum_agg_by_group<S, F>(&self, groups: &UInt32Chunked, init: S, f: F) -> PolarsResult<Self>
+    where
+        S: Copy,
+        F: Fn(&mut S, Option<T::Native>) -> Option<Option<T::Native>>,
+    {
+        if self.len() != groups.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cumulative aggregation by group: groups with length {} does not match array with length {}",
+                    groups.len(),
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        let mut state = init;
+        let mut prev_group = None;
+        let mut ca: Self = self
+            .into_iter()
+            .zip(groups)
+            .enumerate()
+            .map(|(i, (opt_v, group))| {
+                // a new run of group labels resets the state
+                if i == 0 || prev_group != group {
+                    state = init;
+                    prev_group = group;
+                }
+                f(&mut state, opt_v).unwrap()
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Get an array with the cumulative max computed at every element, restarting
+    /// whenever the label in `groups` changes.
+    pub fn cummax_by_group(&self, groups: &UInt32Chunked) -> PolarsResult<Self> {
+        self.cum_agg_by_group(groups, Bounded::min_value(), det_max)
+    }
+
+    /// Get an array with the cumulative min computed at every element, restarting
+    /// whenever the label in `groups` changes.
+    pub fn cummin_by_group(&self, groups: &UInt32Chunked) -> PolarsResult<Self> {
+        self.cum_agg_by_group(groups, Bounded::max_value(), det_min)
+    }
+
+    /// Get an array with the cumulative sum computed at every element, restarting
+    /// whenever the label in `groups` changes.
+    pub fn cumsum_by_group(&self, groups: &UInt32Chunked) -> PolarsResult<Self> {
+        self.cum_agg_by_group(groups, None, det_sum)
+    }
+
+    /// Get an array with the cumulative product computed at every element, restarting
+    /// whenever the label in `groups` changes.
+    pub fn cumprod_by_group(&self, groups: &UInt32Chunked) -> PolarsResult<Self> {
+        self.cum_agg_by_group(groups, None, det_prod)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -173,4 +237,26 @@ mod test {
         let ca = Float32Chunked::new("foo", &[None, Some(1.0), Some(3.0), None, Some(1.0)]);
         let _out = ca.cumsum(false);
     }
+
+    #[test]
+    fn test_cum_agg_by_group() {
+        let ca = Int32Chunked::new("foo", &[Some(1), Some(2), None, Some(3), Some(4), Some(5)]);
+        let groups = UInt32Chunked::new("groups", &[0, 0, 0, 1, 1, 0]);
+        let out = ca.cumsum_by_group(&groups).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(3), None, Some(3), Some(7), Some(5)]
+        );
+        let out = ca.cummax_by_group(&groups).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(2), None, Some(3), Some(4), Some(5)]
+        );
+        let out = ca.cumprod_by_group(&groups).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(2), None, Some(3), Some(12), Some(5)]
+        );
+        assert!(ca.cummin_by_group(&groups.slice(0, 2)).is_err());
+    }
 }
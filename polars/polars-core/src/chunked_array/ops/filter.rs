@@ -1,11 +1,14 @@
 #[cfg(feature = "object")]
 use arrow::array::Array;
 use arrow::compute::filter::filter as filter_fn;
+use rayon::prelude::*;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
 use crate::prelude::*;
-use crate::utils::align_chunks_binary;
+use crate::series::IsSorted;
+use crate::utils::{_split_offsets, align_chunks_binary};
+use crate::POOL;
 
 macro_rules! check_filter_len {
     ($self:expr, $filter:expr) => {{
@@ -187,3 +190,153 @@ where
         Ok(builder.finish())
     }
 }
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkFilter<T>,
+{
+    /// Filter values in the ChunkedArray with a boolean mask, like [`ChunkFilter::filter`],
+    /// but split both arrays into one slice per thread and filter the slices in parallel.
+    /// The output has at least one chunk per thread.
+    pub fn par_filter(&self, filter: &BooleanChunked) -> PolarsResult<Self> {
+        let n_threads = POOL.current_num_threads();
+        // broadcast, or too small to split
+        if filter.len() == 1 || n_threads == 1 || self.len() < n_threads {
+            return self.filter(filter);
+        }
+        if self.len() != filter.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "Filter's length differs from that of the ChunkedArray. \
+                Length Self: {} Length mask: {}",
+                    self.len(),
+                    filter.len(),
+                )
+                .into(),
+            ));
+        }
+        let offsets = _split_offsets(self.len(), n_threads);
+        let parts = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    self.slice(offset as i64, len)
+                        .filter(&filter.slice(offset as i64, len))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let chunks = parts
+            .into_iter()
+            .flat_map(|part| part.chunks)
+            .collect::<Vec<_>>();
+        Ok(self.copy_with_chunks(chunks, true))
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T> {
+    /// Set the values where `mask` is `false` or null to null, without compacting the array
+    /// like [`ChunkFilter::filter`] does. Only the validity bitmaps are replaced; the value
+    /// buffers are not touched.
+    pub fn apply_mask_inplace(&mut self, mask: &BooleanChunked) -> PolarsResult<()> {
+        if self.len() != mask.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "Mask's length differs from that of the ChunkedArray. \
+                Length Self: {} Length mask: {}",
+                    self.len(),
+                    mask.len(),
+                )
+                .into(),
+            ));
+        }
+        let mask = mask.match_chunks(self.chunk_id());
+        // safety:
+        // only the validity changes, the lengths and data types remain the same
+        let chunks = unsafe { self.chunks_mut() };
+        for (arr, mask) in chunks.iter_mut().zip(mask.downcast_iter()) {
+            // nulls in the mask don't select a value
+            let keep = match mask.validity() {
+                Some(validity) => mask.values() & validity,
+                None => mask.values().clone(),
+            };
+            let validity = match arr.validity() {
+                Some(validity) => validity & &keep,
+                None => keep,
+            };
+            *arr = arr.with_validity(Some(validity));
+        }
+        // new nulls may have been inserted anywhere
+        self.set_sorted_flag(IsSorted::Not);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_par_filter() -> PolarsResult<()> {
+        let values = (0..10_000)
+            .map(|v| (v % 7 != 0).then_some(v))
+            .collect::<Vec<_>>();
+        let ca = Int32Chunked::new("a", &values);
+        let utf8 = ca.cast(&DataType::Utf8)?;
+        let utf8 = utf8.utf8()?;
+
+        // a simple linear congruential generator gives reproducible random masks
+        let mut state = 42u64;
+        for density in [0.0, 0.01, 0.5, 0.99, 1.0] {
+            let mask = (0..ca.len())
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((state >> 33) as f64 / (1u64 << 31) as f64) < density
+                })
+                .collect::<BooleanChunked>();
+
+            let out = ca.par_filter(&mask)?;
+            assert_eq!(out.name(), "a");
+            assert_eq!(Vec::from(&out), Vec::from(&ca.filter(&mask)?));
+            assert_eq!(
+                Vec::from(&utf8.par_filter(&mask)?),
+                Vec::from(&utf8.filter(&mask)?)
+            );
+        }
+
+        assert_eq!(
+            ca.par_filter(&BooleanChunked::new("", &[true]))?.len(),
+            ca.len()
+        );
+        assert!(ca
+            .par_filter(&BooleanChunked::new("", &[true, false]))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_mask_inplace() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a", &[Some(1), None, Some(3), Some(4)]);
+        ca.append(&Int32Chunked::new("a", &[5, 6]));
+        let mut mask = BooleanChunked::new("", &[true, true, false]);
+        mask.append(&BooleanChunked::new("", &[Some(true), None, Some(true)]));
+
+        ca.apply_mask_inplace(&mask)?;
+        assert_eq!(ca.len(), 6);
+        assert_eq!(
+            Vec::from(&ca),
+            &[Some(1), None, None, Some(4), None, Some(6)]
+        );
+        // the values buffers are untouched
+        assert_eq!(
+            ca.downcast_iter().next().unwrap().values().as_slice(),
+            &[1, 0, 3, 4]
+        );
+
+        assert!(ca
+            .apply_mask_inplace(&BooleanChunked::new("", &[true]))
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use arrow::array::ListArray;
+use arrow::offset::Offsets;
+
+use crate::prelude::*;
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// View a flat array of length `n` as a [`ListChunked`] of `n / width` lists that
+    /// each hold `width` consecutive values, e.g. to treat embeddings that are stored
+    /// as one flat array as a column of vectors.
+    ///
+    /// The values buffer is shared with `self`, only the offsets are allocated. If `self`
+    /// consists of multiple chunks it is rechunked first.
+    ///
+    /// Returns an error if `width == 0` or if `n` is not a multiple of `width`.
+    pub fn view_as_fixed_width_list(&self, width: usize) -> PolarsResult<ListChunked> {
+        if width == 0 {
+            return Err(PolarsError::ComputeError(
+                "'width' in 'view_as_fixed_width_list' must be positive".into(),
+            ));
+        }
+        let n = self.len();
+        if n % width != 0 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!("array of length {n} cannot be split into lists of width {width}").into(),
+            ));
+        }
+        let ca = self.rechunk();
+        let values = ca.chunks()[0].clone();
+
+        let offsets = (0..=n / width)
+            .map(|i| (i * width) as i64)
+            .collect::<Vec<_>>();
+        let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+        // Safety: offsets are monotonically increasing and end at `n`
+        let arr = ListArray::<i64>::new(
+            data_type,
+            unsafe { Offsets::new_unchecked(offsets).into() },
+            values,
+            None,
+        );
+        let mut out = unsafe { ListChunked::from_chunks(self.name(), vec![Box::new(arr)]) };
+        out.set_fast_explode();
+        Ok(out)
+    }
+
+    /// Build a [`ListChunked`] named `name` from a flat `values` array and Arrow style
+    /// `offsets`: list `i` holds the values `offsets[i]..offsets[i + 1]`.
+    ///
+    /// The values buffer is shared with `values`, only the offsets are copied. If `values`
+    /// consists of multiple chunks it is rechunked first.
+    ///
+    /// Returns an error if `offsets` is empty, negative or decreasing, or if the last offset
+    /// exceeds the length of `values`.
+    pub fn from_offsets(values: Self, offsets: &[i64], name: &str) -> PolarsResult<ListChunked> {
+        let (first, last) = match (offsets.first(), offsets.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    "'offsets' must contain at least one offset".into(),
+                ))
+            }
+        };
+        if first < 0 || offsets.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::ComputeError(
+                "'offsets' must be non-negative and monotonically increasing".into(),
+            ));
+        }
+        if last as usize > values.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "last offset {last} exceeds the length {} of the values",
+                    values.len()
+                )
+                .into(),
+            ));
+        }
+        let ca = values.rechunk();
+        let values = ca.chunks()[0].clone();
+
+        let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+        // Safety: offsets are checked to be monotonically increasing and in bounds
+        let arr = ListArray::<i64>::new(
+            data_type,
+            unsafe { Offsets::new_unchecked(offsets.to_vec()).into() },
+            values,
+            None,
+        );
+        Ok(unsafe { ListChunked::from_chunks(name, vec![Box::new(arr)]) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_view_as_fixed_width_list() -> PolarsResult<()> {
+        let ca = Float32Chunked::new("a", &[Some(1.0), Some(2.0), None, Some(4.0)]);
+        let out = ca.view_as_fixed_width_list(2)?;
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.inner_dtype(), DataType::Float32);
+        let second = out.get(1).unwrap();
+        assert_eq!(Vec::from(second.f32()?), &[None, Some(4.0)]);
+
+        // the values buffer is shared
+        let values = out.downcast_iter().next().unwrap().values();
+        let values = values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f32>>()
+            .unwrap();
+        assert_eq!(
+            values.values().as_ptr(),
+            ca.downcast_iter().next().unwrap().values().as_ptr()
+        );
+
+        assert!(ca.view_as_fixed_width_list(0).is_err());
+        assert!(ca.view_as_fixed_width_list(3).is_err());
+        assert_eq!(
+            Int32Chunked::full_null("a", 0)
+                .view_as_fixed_width_list(2)?
+                .len(),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_offsets() -> PolarsResult<()> {
+        let values = Int32Chunked::new("v", &[Some(1), Some(2), None, Some(4), Some(5)]);
+        let out = Int32Chunked::from_offsets(values.clone(), &[0, 2, 2, 4], "a")?;
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.inner_dtype(), DataType::Int32);
+        assert_eq!(Vec::from(out.get(0).unwrap().i32()?), &[Some(1), Some(2)]);
+        assert_eq!(out.get(1).unwrap().len(), 0);
+        assert_eq!(Vec::from(out.get(2).unwrap().i32()?), &[None, Some(4)]);
+
+        // the values buffer is shared
+        let arr = out.downcast_iter().next().unwrap().values();
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        assert_eq!(
+            arr.values().as_ptr(),
+            values.downcast_iter().next().unwrap().values().as_ptr()
+        );
+
+        assert_eq!(
+            Int32Chunked::from_offsets(values.clone(), &[0], "a")?.len(),
+            0
+        );
+        assert!(Int32Chunked::from_offsets(values.clone(), &[], "a").is_err());
+        assert!(Int32Chunked::from_offsets(values.clone(), &[0, 3, 2], "a").is_err());
+        assert!(Int32Chunked::from_offsets(values.clone(), &[-1, 2], "a").is_err());
+        assert!(Int32Chunked::from_offsets(values, &[0, 6], "a").is_err());
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ use std::marker::Sized;
 use arrow::offset::OffsetsBuffer;
 use polars_arrow::prelude::QuantileInterpolOptions;
 
+#[cfg(feature = "repeat_by")]
+pub use self::repeat_by::rle_decode;
 pub use self::take::*;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectType;
@@ -17,9 +19,11 @@ pub(crate) mod append;
 mod apply;
 mod bit_repr;
 pub(crate) mod chunkops;
+mod combinations;
 pub(crate) mod compare_inner;
 #[cfg(feature = "concat_str")]
 mod concat_str;
+mod count;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 pub(crate) mod downcast;
@@ -27,12 +31,16 @@ pub(crate) mod explode;
 mod extend;
 mod fill_null;
 mod filter;
+mod fixed_width_list;
 pub mod full;
 #[cfg(feature = "interpolate")]
 mod interpolate;
 #[cfg(feature = "is_in")]
 mod is_in;
 mod len;
+mod monotonic;
+mod normalize;
+mod pairwise;
 mod peaks;
 #[cfg(feature = "repeat_by")]
 mod repeat_by;
@@ -41,6 +49,7 @@ pub(crate) mod rolling_window;
 mod set;
 mod shift;
 pub(crate) mod sort;
+pub(crate) mod statistics;
 pub(crate) mod take;
 pub(crate) mod unique;
 #[cfg(feature = "zip_with")]
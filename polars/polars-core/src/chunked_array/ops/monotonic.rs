@@ -0,0 +1,89 @@
+use crate::prelude::*;
+use crate::series::IsSorted;
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn is_monotonic_impl(&self, increasing: bool) -> bool {
+        let mut iter = self.into_iter().flatten();
+        let mut prev = match iter.next() {
+            Some(v) => v,
+            None => return true,
+        };
+        for v in iter {
+            let in_order = if increasing { v >= prev } else { v <= prev };
+            if !in_order {
+                return false;
+            }
+            prev = v;
+        }
+        true
+    }
+
+    /// Check if every value is greater than or equal to the previous non-null value.
+    /// Null values are ignored.
+    pub fn is_monotonic_increasing(&self) -> bool {
+        match self.is_sorted_flag2() {
+            IsSorted::Ascending => true,
+            _ => self.is_monotonic_impl(true),
+        }
+    }
+
+    /// Check if every value is less than or equal to the previous non-null value.
+    /// Null values are ignored.
+    pub fn is_monotonic_decreasing(&self) -> bool {
+        match self.is_sorted_flag2() {
+            IsSorted::Descending => true,
+            _ => self.is_monotonic_impl(false),
+        }
+    }
+
+    /// Get a mask that is `true` where the value is strictly greater (`increasing`) or
+    /// strictly less (`!increasing`) than the previous non-null value.
+    ///
+    /// The first non-null value is `false` and null values stay null.
+    pub fn monotonic_mask(&self, increasing: bool) -> BooleanChunked {
+        let mut prev = None;
+        let mut out: BooleanChunked = self
+            .into_iter()
+            .map(|opt_v| {
+                opt_v.map(|v| {
+                    let out = match prev {
+                        Some(prev) if increasing => v > prev,
+                        Some(prev) => v < prev,
+                        None => false,
+                    };
+                    prev = Some(v);
+                    out
+                })
+            })
+            .collect_trusted();
+        out.rename(self.name());
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_monotonic() {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(1), Some(3)]);
+        assert!(ca.is_monotonic_increasing());
+        assert!(!ca.is_monotonic_decreasing());
+        assert_eq!(
+            Vec::from(&ca.monotonic_mask(true)),
+            &[Some(false), None, Some(false), Some(true)]
+        );
+
+        let ca = Float64Chunked::new("a", &[3.0, 2.0, 2.5]);
+        assert!(!ca.is_monotonic_increasing());
+        assert!(!ca.is_monotonic_decreasing());
+        assert_eq!(
+            Vec::from(&ca.monotonic_mask(false)),
+            &[Some(false), Some(true), Some(false)]
+        );
+    }
+}
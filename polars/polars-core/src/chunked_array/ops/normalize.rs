@@ -0,0 +1,77 @@
+use num::ToPrimitive;
+
+use crate::prelude::*;
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: ChunkAgg<T::Native>,
+{
+    /// Scale the values linearly to the range `[0, 1]`.
+    ///
+    /// Returns an error if all the (non-null) values are equal.
+    pub fn min_max_normalize(&self) -> PolarsResult<Float64Chunked> {
+        let (min, max) = match (self.min(), self.max()) {
+            (Some(min), Some(max)) => (min.to_f64().unwrap(), max.to_f64().unwrap()),
+            _ => return Ok(Float64Chunked::full_null(self.name(), self.len())),
+        };
+        let range = max - min;
+        if range == 0.0 {
+            return Err(PolarsError::ComputeError(
+                "cannot min-max normalize: the minimum is equal to the maximum".into(),
+            ));
+        }
+        Ok(self.apply_cast_numeric(|v| (v.to_f64().unwrap() - min) / range))
+    }
+
+    /// Subtract the mean from the values and divide them by the (sample) standard deviation.
+    ///
+    /// Returns an error if the standard deviation is zero or undefined.
+    pub fn z_score_normalize(&self) -> PolarsResult<Float64Chunked> {
+        if self.null_count() == self.len() {
+            return Ok(Float64Chunked::full_null(self.name(), self.len()));
+        }
+        // Welford's online algorithm
+        let mut n = 0.0;
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        for arr in self.downcast_iter() {
+            for v in arr.iter().flatten() {
+                let v = v.to_f64().unwrap();
+                n += 1.0;
+                let delta = v - mean;
+                mean += delta / n;
+                m2 += delta * (v - mean);
+            }
+        }
+        let std = (m2 / (n - 1.0)).sqrt();
+        if std == 0.0 || std.is_nan() {
+            return Err(PolarsError::ComputeError(
+                "cannot z-score normalize: the standard deviation is zero or undefined".into(),
+            ));
+        }
+        Ok(self.apply_cast_numeric(|v| (v.to_f64().unwrap() - mean) / std))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_normalize() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(2), None, Some(4), Some(6)]);
+
+        let out = ca.min_max_normalize()?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(0.0), None, Some(0.5), Some(1.0)]);
+
+        let out = ca.z_score_normalize()?;
+        assert_eq!(Vec::from(&out), &[Some(-1.0), None, Some(0.0), Some(1.0)]);
+
+        let ca = Float64Chunked::new("a", &[Some(1.0), None, Some(1.0)]);
+        assert!(ca.min_max_normalize().is_err());
+        assert!(ca.z_score_normalize().is_err());
+        Ok(())
+    }
+}
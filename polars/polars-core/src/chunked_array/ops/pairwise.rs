@@ -0,0 +1,184 @@
+use rayon::prelude::*;
+
+use crate::prelude::*;
+use crate::POOL;
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Apply `f` to all pairs of values `(i, j)` where `i < j`.
+    ///
+    /// The output has length `n * (n - 1) / 2` and is ordered as the condensed upper
+    /// triangle of a pairwise matrix: `(0, 1), (0, 2), ..., (0, n - 1), (1, 2), ...`.
+    /// Pairs involving a null value are null.
+    ///
+    /// Returns an error if the output would take more than `max_bytes` of memory.
+    pub fn pairwise_apply<F>(&self, f: F, max_bytes: usize) -> PolarsResult<Float64Chunked>
+    where
+        F: Fn(T::Native, T::Native) -> f64,
+    {
+        let n = self.len();
+        let out_len = n
+            .checked_mul(n.saturating_sub(1))
+            .map(|n_pairs| n_pairs / 2);
+        let n_bytes = out_len.and_then(|out_len| out_len.checked_mul(std::mem::size_of::<f64>()));
+        let out_len = match (out_len, n_bytes) {
+            (Some(out_len), Some(n_bytes)) if n_bytes <= max_bytes => out_len,
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "pairwise_apply on {n} values would exceed the limit of {max_bytes} bytes"
+                    )
+                    .into(),
+                ))
+            }
+        };
+
+        if self.null_count() == 0 {
+            let values = self.into_no_null_iter().collect::<Vec<_>>();
+            let mut out = Vec::with_capacity(out_len);
+            for (i, a) in values.iter().enumerate() {
+                out.extend(values[i + 1..].iter().map(|b| f(*a, *b)));
+            }
+            Ok(Float64Chunked::from_vec(self.name(), out))
+        } else {
+            let values = self.into_iter().collect::<Vec<_>>();
+            let mut out = Vec::with_capacity(out_len);
+            for (i, a) in values.iter().enumerate() {
+                out.extend(values[i + 1..].iter().map(|b| match (a, b) {
+                    (Some(a), Some(b)) => Some(f(*a, *b)),
+                    _ => None,
+                }));
+            }
+            Ok(Float64Chunked::from_iter_options(
+                self.name(),
+                out.into_iter(),
+            ))
+        }
+    }
+
+    /// Compute the symmetric `n x n` matrix of `metric` applied to all pairs of values.
+    ///
+    /// Column `j` of the output holds the distances of all values to value `j`. The columns
+    /// are named by `names`, or by their index if `names` is `None`. The diagonal is `0.0`
+    /// and distances involving a null value are null. The rows of the upper triangle are
+    /// computed in parallel.
+    ///
+    /// Returns an error if `n` is larger than `max_size`.
+    pub fn pairwise_distance<F>(
+        &self,
+        metric: F,
+        names: Option<&[&str]>,
+        max_size: usize,
+    ) -> PolarsResult<DataFrame>
+    where
+        F: Fn(T::Native, T::Native) -> f64 + Send + Sync,
+    {
+        let n = self.len();
+        if n > max_size {
+            return Err(PolarsError::ComputeError(
+                format!("pairwise_distance on {n} values exceeds the limit of {max_size} values")
+                    .into(),
+            ));
+        }
+        if let Some(names) = names {
+            if names.len() != n {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "got {} names for a pairwise distance matrix of {n} values",
+                        names.len()
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        let values = self.into_iter().collect::<Vec<_>>();
+        let dist = |a: Option<T::Native>, b: Option<T::Native>| match (a, b) {
+            (Some(a), Some(b)) => Some(metric(a, b)),
+            _ => None,
+        };
+        // row `i` holds the distances of value `i` to the values `i + 1..n`
+        let upper = POOL.install(|| {
+            (0..n)
+                .into_par_iter()
+                .map(|i| {
+                    values[i + 1..]
+                        .iter()
+                        .map(|b| dist(values[i], *b))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let columns = (0..n)
+            .map(|j| {
+                let ca: Float64Chunked = (0..n)
+                    .map(|i| match i.cmp(&j) {
+                        std::cmp::Ordering::Less => upper[i][j - i - 1],
+                        std::cmp::Ordering::Greater => upper[j][i - j - 1],
+                        std::cmp::Ordering::Equal => values[i].map(|_| 0.0),
+                    })
+                    .collect_trusted();
+                let name = match names {
+                    Some(names) => names[j].to_string(),
+                    None => j.to_string(),
+                };
+                ca.with_name(&name).into_series()
+            })
+            .collect::<Vec<_>>();
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pairwise_apply() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[1, 4, 6]);
+        let out = ca.pairwise_apply(|a, b| (a - b).abs() as f64, usize::MAX)?;
+        assert_eq!(Vec::from(&out), &[Some(3.0), Some(5.0), Some(2.0)]);
+
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(6)]);
+        let out = ca.pairwise_apply(|a, b| (a - b).abs() as f64, usize::MAX)?;
+        assert_eq!(Vec::from(&out), &[None, Some(5.0), None]);
+
+        assert!(ca.pairwise_apply(|a, b| (a - b) as f64, 16).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pairwise_distance() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), Some(4), None, Some(6)]);
+        let df = ca.pairwise_distance(|a, b| (a - b).abs() as f64, None, 10)?;
+        assert_eq!(df.get_column_names(), &["0", "1", "2", "3"]);
+        assert_eq!(
+            Vec::from(df.column("0")?.f64()?),
+            &[Some(0.0), Some(3.0), None, Some(5.0)]
+        );
+        assert_eq!(Vec::from(df.column("2")?.f64()?), &[None, None, None, None]);
+        assert_eq!(
+            Vec::from(df.column("3")?.f64()?),
+            &[Some(5.0), Some(2.0), None, Some(0.0)]
+        );
+
+        let df =
+            ca.pairwise_distance(|a, b| (a - b).abs() as f64, Some(&["w", "x", "y", "z"]), 4)?;
+        assert_eq!(df.get_column_names(), &["w", "x", "y", "z"]);
+        assert_eq!(
+            Vec::from(df.column("x")?.f64()?),
+            &[Some(3.0), Some(0.0), None, Some(2.0)]
+        );
+
+        assert!(ca
+            .pairwise_distance(|a, b| (a - b) as f64, None, 3)
+            .is_err());
+        assert!(ca
+            .pairwise_distance(|a, b| (a - b) as f64, Some(&["x"]), 10)
+            .is_err());
+        Ok(())
+    }
+}
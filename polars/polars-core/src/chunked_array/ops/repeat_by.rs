@@ -88,3 +88,159 @@ impl RepeatBy for BinaryChunked {
         }
     }
 }
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTake,
+{
+    /// Repeat the value at every position `i` exactly `by[i]` times and concatenate the
+    /// repetitions, so the output has length `by.sum()`. This is the flat counterpart of
+    /// [`RepeatBy::repeat_by`] and the inverse of a run-length encoding.
+    ///
+    /// Returns an error if the lengths differ or if `by` contains a null.
+    pub fn repeat_by_flat(&self, by: &IdxCa) -> PolarsResult<Self> {
+        if self.len() != by.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "expected 'by' of length {}, got length {}",
+                    self.len(),
+                    by.len()
+                )
+                .into(),
+            ));
+        }
+        if by.null_count() > 0 {
+            return Err(PolarsError::ComputeError(
+                "'by' in 'repeat_by_flat' may not contain nulls".into(),
+            ));
+        }
+        let mut idx = Vec::with_capacity(by.sum().unwrap_or(0) as usize);
+        for (i, n) in by.into_no_null_iter().enumerate() {
+            idx.extend(std::iter::repeat(i as IdxSize).take(n as usize));
+        }
+        let idx = IdxCa::from_vec("", idx);
+        // Safety: the indices are in bounds
+        Ok(unsafe { self.take_unchecked((&idx).into()) })
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTake + for<'a> ChunkCompare<&'a ChunkedArray<T>, Item = BooleanChunked>,
+{
+    /// Run-length encode the array. Every run of consecutive equal values is collapsed
+    /// into its value and the length of the run, so both outputs have one element per
+    /// run. Nulls are considered equal to each other, so consecutive nulls form a
+    /// single run.
+    ///
+    /// This is the inverse of [`rle_decode`].
+    pub fn rle(&self) -> (Self, IdxCa) {
+        let len = self.len();
+        let mut starts = Vec::with_capacity(1);
+        if len > 0 {
+            starts.push(0 as IdxSize);
+        }
+        if len > 1 {
+            let tail = self.slice(1, len - 1);
+            let head = self.slice(0, len - 1);
+            let changed = tail.not_equal(&head).fill_null_with_values(false).unwrap();
+            let null_changed = &tail.is_null() ^ &head.is_null();
+            let boundary = &changed | &null_changed;
+            starts.extend(
+                boundary
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, b)| b.unwrap_or(false).then_some((i + 1) as IdxSize)),
+            );
+        }
+        let lengths = starts
+            .iter()
+            .zip(
+                starts
+                    .iter()
+                    .skip(1)
+                    .chain(std::iter::once(&(len as IdxSize))),
+            )
+            .map(|(start, end)| end - start)
+            .collect::<Vec<_>>();
+
+        let starts = IdxCa::from_vec("", starts);
+        // Safety: the run starts are in bounds
+        let values = unsafe { self.take_unchecked((&starts).into()) };
+        (values, IdxCa::from_vec("lengths", lengths))
+    }
+}
+
+/// Decode a run-length encoding as produced by [`ChunkedArray::rle`], repeating every
+/// value `lengths[i]` times.
+///
+/// Returns an error if the lengths differ or if `lengths` contains a null.
+pub fn rle_decode<T: PolarsDataType>(
+    values: &ChunkedArray<T>,
+    lengths: &IdxCa,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    ChunkedArray<T>: ChunkTake,
+{
+    values.repeat_by_flat(lengths)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repeat_by_flat() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(3)]);
+        let by = IdxCa::new("by", &[2, 1, 0]);
+        let out = ca.repeat_by_flat(&by)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(1), Some(1), None]);
+
+        let ca = Utf8Chunked::new("a", &["x", "y"]);
+        let out = ca.repeat_by_flat(&IdxCa::new("by", &[1, 3]))?;
+        assert_eq!(out.len(), 4);
+
+        assert!(ca.repeat_by_flat(&IdxCa::new("by", &[1])).is_err());
+        assert!(ca
+            .repeat_by_flat(&IdxCa::new("by", &[Some(1), None]))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rle() -> PolarsResult<()> {
+        let ca = Int32Chunked::new(
+            "a",
+            &[
+                Some(1),
+                Some(1),
+                None,
+                None,
+                Some(2),
+                Some(1),
+                Some(1),
+                None,
+            ],
+        );
+        let (values, lengths) = ca.rle();
+        assert_eq!(Vec::from(&values), &[Some(1), None, Some(2), Some(1), None]);
+        assert_eq!(
+            Vec::from(&lengths),
+            &[Some(2), Some(2), Some(1), Some(2), Some(1)]
+        );
+        assert!(rle_decode(&values, &lengths)?
+            .into_series()
+            .series_equal_missing(&ca.into_series()));
+
+        let ca = Utf8Chunked::new("a", &["x", "x", "y"]);
+        let (values, lengths) = ca.rle();
+        assert_eq!(Vec::from(&values), &[Some("x"), Some("y")]);
+        assert_eq!(Vec::from(&lengths), &[Some(2), Some(1)]);
+
+        let (values, lengths) = Int32Chunked::new("a", &[] as &[i32]).rle();
+        assert_eq!(values.len(), 0);
+        assert_eq!(lengths.len(), 0);
+        Ok(())
+    }
+}
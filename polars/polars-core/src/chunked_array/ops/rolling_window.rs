@@ -31,6 +31,7 @@ mod inner_mod {
     use num::{Float, Zero};
     use polars_arrow::bit_util::unset_bit_raw;
     use polars_arrow::data_types::IsFloat;
+    use polars_arrow::kernels::rolling;
     use polars_arrow::trusted_len::PushUnchecked;
 
     use crate::prelude::*;
@@ -178,6 +179,103 @@ mod inner_mod {
         }
     }
 
+    type SlidingFnNoNulls<T> = fn(&[T], usize, usize, bool, Option<&[f64]>) -> ArrayRef;
+    type SlidingFnNulls<T> = fn(&PrimitiveArray<T>, usize, usize, bool, Option<&[f64]>) -> ArrayRef;
+
+    impl<T> ChunkedArray<T>
+    where
+        T: PolarsNumericType,
+    {
+        fn sliding_agg(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            weights: Option<&[f64]>,
+            center: bool,
+            agg_fn: SlidingFnNoNulls<T::Native>,
+            agg_fn_nulls: SlidingFnNulls<T::Native>,
+        ) -> PolarsResult<Self> {
+            check_input(window_size, min_periods)?;
+            if window_size == 0 {
+                return Err(PolarsError::ComputeError(
+                    "`window_size` should be > 0".into(),
+                ));
+            }
+            if let Some(weights) = weights {
+                if !T::Native::is_float() {
+                    return Err(PolarsError::InvalidOperation(
+                        "weights are only supported on float types".into(),
+                    ));
+                }
+                if self.null_count() > 0 {
+                    return Err(PolarsError::InvalidOperation(
+                        "weights not yet supported on array with null values".into(),
+                    ));
+                }
+                if weights.len() != window_size {
+                    return Err(PolarsError::ShapeMisMatch(
+                        "the length of the weights should be equal to `window_size`".into(),
+                    ));
+                }
+            }
+            if self.is_empty() {
+                return Ok(self.clone());
+            }
+
+            let ca = self.rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let out = if arr.null_count() == 0 {
+                agg_fn(
+                    arr.values().as_slice(),
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                )
+            } else {
+                agg_fn_nulls(arr, window_size, min_periods, center, weights)
+            };
+            // safety: the kernels return an array of the same type
+            unsafe { Ok(Self::from_chunks(self.name(), vec![out])) }
+        }
+
+        /// Apply a rolling min over the values, in O(n) regardless of the `window_size`.
+        pub fn sliding_min(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            weights: Option<&[f64]>,
+            center: bool,
+        ) -> PolarsResult<Self> {
+            self.sliding_agg(
+                window_size,
+                min_periods,
+                weights,
+                center,
+                rolling::no_nulls::sliding_min,
+                rolling::nulls::sliding_min,
+            )
+        }
+
+        /// Apply a rolling max over the values, in O(n) regardless of the `window_size`.
+        pub fn sliding_max(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            weights: Option<&[f64]>,
+            center: bool,
+        ) -> PolarsResult<Self> {
+            self.sliding_agg(
+                window_size,
+                min_periods,
+                weights,
+                center,
+                rolling::no_nulls::sliding_max,
+                rolling::nulls::sliding_max,
+            )
+        }
+    }
+
     impl<T> ChunkedArray<T>
     where
         ChunkedArray<T>: IntoSeries,
@@ -249,6 +347,195 @@ mod inner_mod {
             unsafe { Ok(Self::from_chunks(self.name(), vec![Box::new(arr)])) }
         }
     }
+
+    impl<T> ChunkedArray<T>
+    where
+        T: PolarsNumericType,
+    {
+        fn welford_input(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+        ) -> PolarsResult<Vec<Option<f64>>> {
+            if window_size == 0 {
+                return Err(PolarsError::ComputeError(
+                    "`window_size` should be > 0".into(),
+                ));
+            }
+            check_input(window_size, min_periods)?;
+            let ca = self.cast(&DataType::Float64)?;
+            let values = ca.f64().unwrap().into_iter().collect::<Vec<_>>();
+            Ok(values)
+        }
+
+        /// Apply `f` to the trailing window of `window_size` values ending at every value, e.g.
+        /// a function pointer that is provided through FFI.
+        ///
+        /// `f` receives the values of the window and, per value, whether it is valid; the
+        /// values at the null positions are unspecified. The first `window_size - 1` windows
+        /// are shorter. The output is null where fewer than `min_periods` values of the window
+        /// are valid.
+        pub fn rolling_apply_to_f64<F>(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            f: F,
+        ) -> PolarsResult<Float64Chunked>
+        where
+            F: Fn(&[T::Native], &[bool]) -> f64,
+        {
+            if window_size == 0 {
+                return Err(PolarsError::ComputeError(
+                    "`window_size` should be > 0".into(),
+                ));
+            }
+            check_input(window_size, min_periods)?;
+            let ca = self.rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let values = arr.values().as_slice();
+            let valid = match arr.validity() {
+                Some(validity) => validity.iter().collect::<Vec<_>>(),
+                None => vec![true; arr.len()],
+            };
+
+            // the number of valid values in the window
+            let mut n_valid = 0;
+            let mut out: Float64Chunked = (0..values.len())
+                .map(|i| {
+                    n_valid += valid[i] as usize;
+                    let start = (i + 1).saturating_sub(window_size);
+                    if start > 0 {
+                        n_valid -= valid[start - 1] as usize;
+                    }
+                    if n_valid < min_periods {
+                        None
+                    } else {
+                        Some(f(&values[start..i + 1], &valid[start..i + 1]))
+                    }
+                })
+                .collect_trusted();
+            out.rename(self.name());
+            Ok(out)
+        }
+
+        /// Compute the z-score `(x - rolling_mean) / rolling_std` of every value, where the
+        /// mean and standard deviation are computed over the trailing window of `window_size`
+        /// values ending at that value.
+        ///
+        /// The window statistics are updated online, so this runs in `O(n)`. The output is
+        /// null where the value is null, where fewer than `min_periods` non-null values are in
+        /// the window, or where the standard deviation is zero.
+        pub fn rolling_zscore(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            ddof: u8,
+        ) -> PolarsResult<Float64Chunked> {
+            let values = self.welford_input(window_size, min_periods)?;
+
+            let mut window = WelfordWindow::default();
+            let mut out: Float64Chunked = (0..values.len())
+                .map(|i| {
+                    window.update(&values, i, window_size);
+                    let x = values[i]?;
+                    if window.n < min_periods {
+                        return None;
+                    }
+                    match window.var(ddof) {
+                        Some(var) if var > 0.0 => Some((x - window.mean) / var.sqrt()),
+                        _ => None,
+                    }
+                })
+                .collect_trusted();
+            out.rename(self.name());
+            Ok(out)
+        }
+
+        /// Compute the variance over the trailing window of `window_size` values ending at
+        /// every value.
+        ///
+        /// The window statistics are updated online with Welford's algorithm, which unlike
+        /// the sum of squares formula does not lose precision when the variance is small
+        /// compared to the mean. The output is null where fewer than `min_periods` non-null
+        /// values, or not more than `ddof` values, are in the window.
+        pub fn window_var(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            ddof: u8,
+        ) -> PolarsResult<Float64Chunked> {
+            let values = self.welford_input(window_size, min_periods)?;
+
+            let mut window = WelfordWindow::default();
+            let mut out: Float64Chunked = (0..values.len())
+                .map(|i| {
+                    window.update(&values, i, window_size);
+                    if window.n < min_periods {
+                        None
+                    } else {
+                        window.var(ddof)
+                    }
+                })
+                .collect_trusted();
+            out.rename(self.name());
+            Ok(out)
+        }
+
+        /// Compute the standard deviation over the trailing window of `window_size` values
+        /// ending at every value. See [`ChunkedArray::window_var`].
+        pub fn window_std(
+            &self,
+            window_size: usize,
+            min_periods: usize,
+            ddof: u8,
+        ) -> PolarsResult<Float64Chunked> {
+            let mut out = self.window_var(window_size, min_periods, ddof)?;
+            out.apply_mut(|var| var.sqrt());
+            Ok(out)
+        }
+    }
+
+    /// Running count, mean and sum of squared deviations of the non-null values
+    /// in a sliding window.
+    #[derive(Default)]
+    struct WelfordWindow {
+        n: usize,
+        mean: f64,
+        m2: f64,
+    }
+
+    impl WelfordWindow {
+        /// Move the trailing window of `window_size` values so that it ends at `i`.
+        fn update(&mut self, values: &[Option<f64>], i: usize, window_size: usize) {
+            if let Some(x) = values[i] {
+                self.n += 1;
+                let delta = x - self.mean;
+                self.mean += delta / self.n as f64;
+                self.m2 += delta * (x - self.mean);
+            }
+            if i >= window_size {
+                if let Some(x) = values[i - window_size] {
+                    // Welford's algorithm, extended to remove the values that leave the window
+                    if self.n == 1 {
+                        *self = Self::default();
+                    } else {
+                        self.n -= 1;
+                        let delta = x - self.mean;
+                        self.mean -= delta / self.n as f64;
+                        self.m2 -= delta * (x - self.mean);
+                    }
+                }
+            }
+        }
+
+        fn var(&self, ddof: u8) -> Option<f64> {
+            if self.n <= ddof as usize {
+                None
+            } else {
+                Some(self.m2.max(0.0) / (self.n - ddof as usize) as f64)
+            }
+        }
+    }
 }
 
 #[cfg(feature = "rolling_window")]
@@ -291,6 +291,126 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Get the indices that partition the array around the value at position `pivot_idx` in
+    /// sorted order, without fully sorting it.
+    ///
+    /// The index at `pivot_idx` points to the value that would be there after sorting. The
+    /// indices before it point to values that are smaller or equal, the indices after it to
+    /// values that are greater or equal. Nulls are ordered last and `NaN`s are considered
+    /// greater than any other float. Runs in `O(n)` on average.
+    pub fn arg_partition(&self, pivot_idx: usize) -> PolarsResult<IdxCa> {
+        if pivot_idx >= self.len() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "pivot index {pivot_idx} is out of bounds for an array of length {}",
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        let mut vals = self
+            .into_iter()
+            .enumerate()
+            .map(|(i, opt_v)| (i as IdxSize, opt_v))
+            .collect_trusted::<Vec<_>>();
+        vals.select_nth_unstable_by(pivot_idx, |(_, a), (_, b)| match (a, b) {
+            (Some(a), Some(b)) => compare_fn_nan_max(a, b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+        });
+        let out: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let mut out = out.into_inner();
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Merge two arrays that are flagged as sorted in ascending order into one sorted array
+    /// in `O(n + m)`, without sorting again.
+    ///
+    /// The nulls of both inputs are placed at the start of the output, or at the end if
+    /// `nulls_last` is set. Returns an error if either input is not flagged as sorted.
+    pub fn sorted_merge(a: &Self, b: &Self, nulls_last: bool) -> PolarsResult<Self> {
+        if !a.is_sorted_flag() || !b.is_sorted_flag() {
+            return Err(PolarsError::ComputeError(
+                "sorted_merge requires both arrays to be flagged as sorted in ascending order"
+                    .into(),
+            ));
+        }
+        let null_count = a.null_count() + b.null_count();
+        let mut values = Vec::with_capacity(a.len() + b.len() - null_count);
+        let mut a_iter = a.into_iter().flatten().peekable();
+        let mut b_iter = b.into_iter().flatten().peekable();
+        loop {
+            // take from `a` on ties, so the merge is stable
+            let v = match (a_iter.peek(), b_iter.peek()) {
+                (Some(l), Some(r)) => {
+                    if compare_fn_nan_max(r, l) == Ordering::Less {
+                        b_iter.next()
+                    } else {
+                        a_iter.next()
+                    }
+                }
+                (Some(_), None) => a_iter.next(),
+                (None, Some(_)) => b_iter.next(),
+                (None, None) => break,
+            };
+            values.push(v.unwrap());
+        }
+
+        let mut out = if null_count == 0 {
+            ChunkedArray::from_vec(a.name(), values)
+        } else {
+            let nulls = std::iter::repeat(None).take(null_count);
+            let values = values.into_iter().map(Some);
+            let mut out: ChunkedArray<T> = if nulls_last {
+                values.chain(nulls).collect()
+            } else {
+                nulls.chain(values).collect()
+            };
+            out.rename(a.name());
+            out
+        };
+        out.set_sorted_flag(IsSorted::Ascending);
+        Ok(out)
+    }
+
+    /// Get the value at position `n` in sorted order, without fully sorting the array.
+    ///
+    /// Nulls are ignored, so `None` is returned if `n` is not smaller than the number of
+    /// non-null values. `NaN`s are considered greater than any other float. Runs in `O(n)`
+    /// on average. Returns an error if `n` is out of bounds.
+    pub fn nth_value(&self, n: usize, descending: bool) -> PolarsResult<Option<T::Native>> {
+        if n >= self.len() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "index {n} is out of bounds for an array of length {}",
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        let mut vals = if self.null_count() == 0 {
+            memcpy_values(self)
+        } else {
+            self.into_iter().flatten().collect::<Vec<_>>()
+        };
+        if n >= vals.len() {
+            return Ok(None);
+        }
+        let (_, v, _) = if descending {
+            vals.select_nth_unstable_by(n, |a, b| compare_fn_nan_max(b, a))
+        } else {
+            vals.select_nth_unstable_by(n, compare_fn_nan_max)
+        };
+        Ok(Some(*v))
+    }
+}
+
 #[cfg(feature = "sort_multiple")]
 fn argsort_multiple_numeric<T: PolarsNumericType>(
     ca: &ChunkedArray<T>,
@@ -804,6 +924,32 @@ mod test {
         assert_eq!(idx, expected);
     }
 
+    #[test]
+    fn test_arg_partition() -> PolarsResult<()> {
+        let ca = Float64Chunked::new(
+            "a",
+            &[
+                Some(5.0),
+                None,
+                Some(1.0),
+                Some(f64::NAN),
+                Some(3.0),
+                Some(4.0),
+            ],
+        );
+        // the position of every value in sorted order
+        let positions = [3, 5, 0, 4, 1, 2];
+        for pivot_idx in 0..ca.len() {
+            let idx = Vec::from(&ca.arg_partition(pivot_idx)?);
+            for (i, opt_idx) in idx.iter().enumerate() {
+                let pos = positions[opt_idx.unwrap() as usize];
+                assert_eq!(pos.cmp(&pivot_idx), i.cmp(&pivot_idx));
+            }
+        }
+        assert!(ca.arg_partition(6).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new(
@@ -961,4 +1107,50 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    fn test_sorted_merge() -> PolarsResult<()> {
+        use crate::series::IsSorted;
+
+        let a = Int32Chunked::new("a", &[Some(4), None, Some(1), Some(6)]).sort(false);
+        let b = Int32Chunked::new("b", &[Some(2), Some(4), Some(7), None]).sort(false);
+
+        let out = Int32Chunked::sorted_merge(&a, &b, false)?;
+        assert_eq!(out.name(), "a");
+        let expected = &[Some(1), Some(2), Some(4), Some(4), Some(6), Some(7)];
+        assert_eq!(Vec::from(&out.slice(0, 2)), &[None, None]);
+        assert_eq!(Vec::from(&out.slice(2, 6)), expected);
+        assert!(out.is_sorted_flag());
+
+        let out = Int32Chunked::sorted_merge(&a, &b, true)?;
+        assert_eq!(Vec::from(&out.slice(0, 6)), expected);
+        assert_eq!(Vec::from(&out.slice(6, 2)), &[None, None]);
+        assert!(out.is_sorted_flag());
+
+        let mut empty = Int32Chunked::from_slice("b", &[]);
+        empty.set_sorted_flag(IsSorted::Ascending);
+        let out = Int32Chunked::sorted_merge(&empty, &a, false)?;
+        assert_eq!(Vec::from(&out), Vec::from(&a));
+
+        let unsorted = Int32Chunked::new("b", &[3, 1]);
+        assert!(Int32Chunked::sorted_merge(&a, &unsorted, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_value() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(5), None, Some(1), Some(3), Some(1), None]);
+        assert_eq!(ca.nth_value(0, false)?, Some(1));
+        assert_eq!(ca.nth_value(1, false)?, Some(1));
+        assert_eq!(ca.nth_value(2, false)?, Some(3));
+        assert_eq!(ca.nth_value(0, true)?, Some(5));
+        assert_eq!(ca.nth_value(3, true)?, Some(1));
+        assert_eq!(ca.nth_value(4, false)?, None);
+        assert!(ca.nth_value(6, false).is_err());
+
+        let ca = Float64Chunked::new("a", &[2.0, f64::NAN, 1.0]);
+        assert_eq!(ca.nth_value(1, false)?, Some(2.0));
+        assert!(ca.nth_value(0, true)?.unwrap().is_nan());
+        Ok(())
+    }
 }
@@ -0,0 +1,171 @@
+use std::fmt::{Display, Formatter};
+
+use num::{Bounded, NumCast, ToPrimitive, Zero};
+
+use crate::prelude::*;
+
+/// Truncate an integer sum to `N`, wrapping around at its bounds like integer
+/// arithmetic on `N` does in release builds.
+fn wrapping_from_i128<N: NumericNative>(v: i128) -> N {
+    let modulus = 1i128 << (std::mem::size_of::<N>() * 8);
+    let mut v = v.rem_euclid(modulus);
+    if N::min_value() < N::zero() && v >= modulus / 2 {
+        v -= modulus;
+    }
+    NumCast::from(v).unwrap()
+}
+
+/// Summary statistics of a numeric [`ChunkedArray`], see [`ChunkedArray::col_statistics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStatistics<T: PolarsNumericType> {
+    pub name: String,
+    /// Number of non-null values.
+    pub count: usize,
+    pub null_count: usize,
+    pub mean: Option<f64>,
+    /// Sample standard deviation (`ddof = 1`).
+    pub std: Option<f64>,
+    pub min: Option<T::Native>,
+    pub max: Option<T::Native>,
+    pub sum: T::Native,
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Compute the count, null count, mean, standard deviation, minimum, maximum and sum
+    /// in a single scan over the data.
+    pub fn col_statistics(&self) -> ColumnStatistics<T> {
+        let mut count = 0usize;
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        let mut min: Option<T::Native> = None;
+        let mut max: Option<T::Native> = None;
+        let mut sum = T::Native::zero();
+        // integers are summed in a wider type, so that they don't overflow
+        let mut int_sum = 0i128;
+
+        for arr in self.downcast_iter() {
+            for &v in arr.iter().flatten() {
+                count += 1;
+                if T::Native::is_float() {
+                    sum += v;
+                } else {
+                    int_sum += v.to_i128().unwrap();
+                }
+                match min {
+                    Some(current) if current <= v => {}
+                    _ => min = Some(v),
+                }
+                match max {
+                    Some(current) if current >= v => {}
+                    _ => max = Some(v),
+                }
+                // Welford's online algorithm
+                let v = v.to_f64().unwrap();
+                let delta = v - mean;
+                mean += delta / count as f64;
+                m2 += delta * (v - mean);
+            }
+        }
+        if !T::Native::is_float() {
+            sum = wrapping_from_i128(int_sum);
+        }
+
+        ColumnStatistics {
+            name: self.name().to_string(),
+            count,
+            null_count: self.null_count(),
+            mean: (count > 0).then_some(mean),
+            std: (count > 1).then(|| (m2 / (count - 1) as f64).sqrt()),
+            min,
+            max,
+            sum,
+        }
+    }
+}
+
+impl<T: PolarsNumericType> Display for ColumnStatistics<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn fmt_opt<V: Display>(v: &Option<V>) -> String {
+            match v {
+                Some(v) => v.to_string(),
+                None => "null".to_string(),
+            }
+        }
+        write!(
+            f,
+            "{}: count: {}, null_count: {}, mean: {}, std: {}, min: {}, max: {}, sum: {}",
+            self.name,
+            self.count,
+            self.null_count,
+            fmt_opt(&self.mean),
+            fmt_opt(&self.std),
+            fmt_opt(&self.min),
+            fmt_opt(&self.max),
+            self.sum
+        )
+    }
+}
+
+impl<T> From<&ColumnStatistics<T>> for DataFrame
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    /// A single row `DataFrame` with a column per statistic.
+    fn from(stats: &ColumnStatistics<T>) -> Self {
+        let columns = vec![
+            Series::new("count", &[stats.count as IdxSize]),
+            Series::new("null_count", &[stats.null_count as IdxSize]),
+            Series::new("mean", &[stats.mean]),
+            Series::new("std", &[stats.std]),
+            ChunkedArray::<T>::from_slice_options("min", &[stats.min]).into_series(),
+            ChunkedArray::<T>::from_slice_options("max", &[stats.max]).into_series(),
+            ChunkedArray::<T>::from_slice("sum", &[stats.sum]).into_series(),
+        ];
+        DataFrame::new_no_checks(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_col_statistics() {
+        let ca = Int32Chunked::new("a", &[Some(3), None, Some(1), Some(2)]);
+        let stats = ca.col_statistics();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.mean, Some(2.0));
+        assert_eq!(stats.std, Some(1.0));
+        assert_eq!((stats.min, stats.max, stats.sum), (Some(1), Some(3), 6));
+        assert_eq!(
+            stats.to_string(),
+            "a: count: 3, null_count: 1, mean: 2, std: 1, min: 1, max: 3, sum: 6"
+        );
+
+        let df = DataFrame::from(&stats);
+        assert_eq!(df.shape(), (1, 7));
+        assert_eq!(df.column("max").unwrap().i32().unwrap().get(0), Some(3));
+
+        let stats = Float64Chunked::full_null("b", 2).col_statistics();
+        assert_eq!((stats.count, stats.mean, stats.min), (0, None, None));
+    }
+
+    #[test]
+    fn test_col_statistics_sum_overflow() {
+        // the sum wraps around like `i8` and `u8` arithmetic does
+        let stats = Int8Chunked::new("a", &[100, 100, -56]).col_statistics();
+        assert_eq!(stats.sum, -112);
+        assert_eq!(stats.mean, Some(48.0));
+
+        let stats = UInt8Chunked::new("a", &[200, 100]).col_statistics();
+        assert_eq!((stats.sum, stats.max), (44, Some(200)));
+
+        let stats = Int32Chunked::new("a", &[i32::MAX, 1]).col_statistics();
+        assert_eq!(stats.sum, i32::MIN);
+    }
+}
@@ -1,6 +1,71 @@
 use crate::prelude::*;
 use crate::utils::NoNull;
 
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTakeEvery<T>,
+{
+    /// Traverse and collect every nth element, starting with the first one.
+    ///
+    /// Returns an error if `n == 0`.
+    pub fn take_every_nth(&self, n: usize) -> PolarsResult<Self> {
+        if n == 0 {
+            return Err(PolarsError::ComputeError(
+                "'n' in 'take_every_nth' must be positive".into(),
+            ));
+        }
+        Ok(self.take_every(n))
+    }
+
+    /// Strided slice, similar to NumPy's `arr[offset:offset + len:step]`.
+    ///
+    /// The slice is zero copy, so only the elements that are kept are traversed.
+    /// Returns an error if `step == 0`.
+    pub fn take_slice_step(&self, offset: usize, step: usize, len: usize) -> PolarsResult<Self> {
+        self.slice(offset as i64, len).take_every_nth(step)
+    }
+
+    /// Split a flat array that stores a row-major matrix with `n` columns into one
+    /// [`Series`] per column. Column `i` holds the elements `i, i + n, i + 2n, ...` and is
+    /// named `names[i]`. This is the inverse of interleaving the columns row by row.
+    ///
+    /// Returns an error if `n == 0`, if `names.len() != n` or if `self.len()` is not a
+    /// multiple of `n`.
+    pub fn into_column_splits(&self, n: usize, names: &[&str]) -> PolarsResult<Vec<Series>>
+    where
+        Self: IntoSeries,
+    {
+        if n == 0 {
+            return Err(PolarsError::ComputeError(
+                "'n' in 'into_column_splits' must be positive".into(),
+            ));
+        }
+        if names.len() != n {
+            return Err(PolarsError::ShapeMisMatch(
+                format!("expected {n} column names, got {}", names.len()).into(),
+            ));
+        }
+        if self.len() % n != 0 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "array of length {} cannot be split into {n} columns of equal length",
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let mut s = self.take_slice_step(i, n, self.len() - i)?.into_series();
+                s.rename(name);
+                Ok(s)
+            })
+            .collect()
+    }
+}
+
 impl<T> ChunkTakeEvery<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -72,3 +137,40 @@ impl<T: PolarsObject> ChunkTakeEvery<ObjectType<T>> for ObjectChunked<T> {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_take_every_nth() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(0), Some(1), None, Some(3), Some(4), Some(5)]);
+        assert_eq!(Vec::from(&ca.take_every_nth(2)?), &[Some(0), None, Some(4)]);
+        assert!(ca.take_every_nth(0).is_err());
+
+        assert_eq!(
+            Vec::from(&ca.take_slice_step(1, 3, 5)?),
+            &[Some(1), Some(4)]
+        );
+        assert_eq!(ca.take_slice_step(10, 1, 2)?.len(), 0);
+
+        let ca = Utf8Chunked::new("a", &["a", "b", "c"]);
+        assert_eq!(Vec::from(&ca.take_every_nth(2)?), &[Some("a"), Some("c")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_column_splits() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(0), Some(1), None, Some(3), Some(4), Some(5)]);
+        let cols = ca.into_column_splits(3, &["x", "y", "z"])?;
+        assert_eq!(cols.len(), 3);
+        assert_eq!(cols[0].name(), "x");
+        assert_eq!(Vec::from(cols[0].i32()?), &[Some(0), Some(3)]);
+        assert_eq!(Vec::from(cols[2].i32()?), &[None, Some(5)]);
+
+        assert!(ca.into_column_splits(0, &[]).is_err());
+        assert!(ca.into_column_splits(2, &["x"]).is_err());
+        assert!(ca.into_column_splits(4, &["a", "b", "c", "d"]).is_err());
+        Ok(())
+    }
+}
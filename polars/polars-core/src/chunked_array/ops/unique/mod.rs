@@ -424,10 +424,38 @@ impl ChunkUnique<Float64Type> for Float64Chunked {
     }
 }
 
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkUnique<T> + ChunkTake,
+{
+    /// Get the unique values in the order of their first occurrence.
+    /// Nulls are kept as a single value at the position of the first null.
+    pub fn unique_stable(&self) -> PolarsResult<Self> {
+        let idx = self.arg_unique()?;
+        // Safety:
+        // Indices are in bounds.
+        Ok(unsafe { self.take_unchecked((&idx).into()) })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn unique_stable() {
+        let ca = Int32Chunked::new("a", &[Some(3), None, Some(1), Some(3), None, Some(2)]);
+        assert_eq!(
+            Vec::from(&ca.unique_stable().unwrap()),
+            &[Some(3), None, Some(1), Some(2)]
+        );
+        let ca = Utf8Chunked::new("a", &["b", "a", "b", "c"]);
+        assert_eq!(
+            Vec::from(&ca.unique_stable().unwrap()),
+            &[Some("b"), Some("a"), Some("c")]
+        );
+    }
+
     #[test]
     fn unique() {
         let ca = ChunkedArray::<Int32Type>::from_slice("a", &[1, 2, 3, 2, 1]);
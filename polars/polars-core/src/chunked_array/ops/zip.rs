@@ -1,5 +1,6 @@
 use arrow::compute::if_then_else::if_then_else;
 use polars_arrow::array::default_arrays::FromData;
+use polars_arrow::utils::combine_validities;
 
 use crate::prelude::*;
 use crate::utils::{align_chunks_ternary, CustomIterTools};
@@ -170,20 +171,38 @@ impl ChunkZip<BinaryType> for BinaryChunked {
 }
 
 impl ChunkZip<ListType> for ListChunked {
+    /// Take the lists of `self` where `mask` is true and the lists of `other` where it is
+    /// false. Rows where `mask` is null are null.
     fn zip_with(
         &self,
         mask: &BooleanChunked,
         other: &ChunkedArray<ListType>,
     ) -> PolarsResult<ChunkedArray<ListType>> {
+        if self.len() != mask.len() || other.len() != mask.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "Shape of parameter `mask` and `other` could not be used in zip_with operation"
+                    .into(),
+            ));
+        }
+        if self.inner_dtype() != other.inner_dtype() {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "cannot zip lists of {:?} with lists of {:?}",
+                    self.inner_dtype(),
+                    other.inner_dtype()
+                )
+                .into(),
+            ));
+        }
         let (left, right, mask) = align_chunks_ternary(self, other, mask);
         let chunks = left
             .downcast_iter()
             .zip(right.downcast_iter())
             .zip(mask.downcast_iter())
             .map(|((left_c, right_c), mask_c)| {
-                let mask_c = prepare_mask(mask_c);
-                let arr = if_then_else(&mask_c, left_c, right_c)?;
-                Ok(arr)
+                let arr = if_then_else(&prepare_mask(mask_c), left_c, right_c)?;
+                let validity = combine_validities(arr.validity(), mask_c.validity());
+                Ok(arr.with_validity(validity))
             })
             .collect::<PolarsResult<Vec<_>>>()?;
         unsafe { Ok(ChunkedArray::from_chunks(self.name(), chunks)) }
@@ -213,3 +232,49 @@ impl<T: PolarsObject> ChunkZip<ObjectType<T>> for ObjectChunked<T> {
         Ok(ca)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_list_zip_with() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            &[
+                Series::new("", &[1, 2]),
+                Series::new("", &[3]),
+                Series::new("", &[4]),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            &[
+                Series::new("", &[5]),
+                Series::new("", &[6, 7]),
+                Series::new("", &[8]),
+            ],
+        );
+        let mask = BooleanChunked::new("", &[Some(true), Some(false), None]);
+
+        let out = a.list()?.zip_with(&mask, b.list()?)?;
+        assert_eq!(out.name(), "a");
+        let out = out
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| Vec::from(s.i32().unwrap())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(6), Some(7)]),
+                None
+            ]
+        );
+
+        let c = b.cast(&DataType::List(Box::new(DataType::Float64)))?;
+        assert!(a.list()?.zip_with(&mask, c.list()?).is_err());
+        assert!(a.list()?.zip_with(&mask.slice(0, 2), b.list()?).is_err());
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use rand::distributions::Bernoulli;
 use rand::prelude::*;
 use rand_distr::{Distribution, Normal, Standard, StandardNormal, Uniform};
 
+use crate::frame::groupby::GroupsIndicator;
 use crate::prelude::*;
 use crate::utils::{CustomIterTools, NoNull};
 
@@ -162,14 +163,38 @@ where
     }
 }
 
+/// Divide `n` over groups proportionally to their sizes with the largest remainder
+/// method, so that the shares sum up to exactly `n`.
+fn stratified_sizes(n: usize, group_sizes: &[usize], total: usize) -> Vec<usize> {
+    let mut shares = Vec::with_capacity(group_sizes.len());
+    let mut remainders = Vec::with_capacity(group_sizes.len());
+    for (i, &size) in group_sizes.iter().enumerate() {
+        let exact = n as f64 * size as f64 / total as f64;
+        shares.push(exact.floor() as usize);
+        remainders.push((exact - exact.floor(), i));
+    }
+    let missing = n.saturating_sub(shares.iter().sum());
+    remainders.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap().then(a.1.cmp(&b.1)));
+    for &(_, i) in remainders.iter().take(missing) {
+        shares[i] += 1;
+    }
+    shares
+}
+
 impl DataFrame {
     /// Sample n datapoints from this DataFrame.
+    ///
+    /// If `by` is given, the sample is stratified on that column: every group of equal
+    /// values in `by` contributes to the sample in proportion to its share of the rows.
+    /// The rows are sampled within each group, so they are ordered by group unless
+    /// `shuffle` is set.
     pub fn sample_n(
         &self,
         n: usize,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        by: Option<&str>,
     ) -> PolarsResult<Self> {
         if !with_replacement && n > self.height() {
             return Err(PolarsError::ShapeMisMatch(
@@ -178,9 +203,12 @@ impl DataFrame {
             ));
         }
         // all columns should used the same indices. So we first create the indices.
-        let idx = match with_replacement {
-            true => create_rand_index_with_replacement(n, self.height(), seed),
-            false => create_rand_index_no_replacement(n, self.height(), seed, shuffle),
+        let idx = match (by, with_replacement) {
+            (Some(by), _) => {
+                self.create_stratified_index(n, by, with_replacement, shuffle, seed)?
+            }
+            (None, true) => create_rand_index_with_replacement(n, self.height(), seed),
+            (None, false) => create_rand_index_no_replacement(n, self.height(), seed, shuffle),
         };
         // Safety:
         // indices are within bounds
@@ -196,7 +224,45 @@ impl DataFrame {
         seed: Option<u64>,
     ) -> PolarsResult<Self> {
         let n = (self.height() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement, shuffle, seed)
+        self.sample_n(n, with_replacement, shuffle, seed, None)
+    }
+
+    fn create_stratified_index(
+        &self,
+        n: usize,
+        by: &str,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<IdxCa> {
+        if n == 0 || self.height() == 0 {
+            return Ok(IdxCa::from_vec("", vec![]));
+        }
+        let groups = self.column(by)?.group_tuples(true, true)?;
+        let group_sizes = groups.iter().map(|g| g.len()).collect::<Vec<_>>();
+        let shares = stratified_sizes(n, &group_sizes, self.height());
+
+        let mut idx = Vec::with_capacity(n);
+        for (i, (group, share)) in groups.iter().zip(shares).enumerate() {
+            let group_idx = match group {
+                GroupsIndicator::Idx((_, all)) => IdxCa::from_vec("", all.clone()),
+                GroupsIndicator::Slice([first, len]) => {
+                    IdxCa::from_vec("", (first..first + len).collect())
+                }
+            };
+            // use a different seed per group, otherwise equally sized groups would all
+            // sample the same relative positions
+            let group_seed = seed.map(|seed| seed.wrapping_add(i as u64));
+            let sampled = group_idx.sample_n(share, with_replacement, false, group_seed)?;
+            idx.extend(sampled.into_no_null_iter());
+        }
+        let idx = IdxCa::from_vec("", idx);
+        if shuffle {
+            let len = idx.len();
+            idx.sample_n(len, false, true, seed)
+        } else {
+            Ok(idx)
+        }
     }
 }
 
@@ -276,16 +342,40 @@ mod test {
         .unwrap();
 
         // default samples are random and don't require seeds
-        assert!(df.sample_n(3, false, false, None).is_ok());
+        assert!(df.sample_n(3, false, false, None, None).is_ok());
         assert!(df.sample_frac(0.4, false, false, None).is_ok());
         // with seeding
-        assert!(df.sample_n(3, false, false, Some(0)).is_ok());
+        assert!(df.sample_n(3, false, false, Some(0), None).is_ok());
         assert!(df.sample_frac(0.4, false, false, Some(0)).is_ok());
         // without replacement can not sample more than 100%
         assert!(df.sample_frac(2.0, false, false, Some(0)).is_err());
-        assert!(df.sample_n(3, true, false, Some(0)).is_ok());
+        assert!(df.sample_n(3, true, false, Some(0), None).is_ok());
         assert!(df.sample_frac(0.4, true, false, Some(0)).is_ok());
         // with replacement can sample more than 100%
         assert!(df.sample_frac(2.0, true, false, Some(0)).is_ok());
     }
+
+    #[test]
+    fn test_sample_stratified() -> PolarsResult<()> {
+        let df = df![
+            "label" => &["a", "a", "a", "b", "b", "b", "b", "b", "b", "b"],
+            "value" => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        ]?;
+
+        let out = df.sample_n(5, false, false, Some(0), Some("label"))?;
+        assert_eq!(out.height(), 5);
+        let labels = out.column("label")?.utf8()?;
+        assert_eq!(labels.into_no_null_iter().filter(|l| *l == "a").count(), 2);
+        assert_eq!(labels.into_no_null_iter().filter(|l| *l == "b").count(), 3);
+
+        let out = df.sample_n(20, true, true, Some(0), Some("label"))?;
+        let labels = out.column("label")?.utf8()?;
+        assert_eq!(labels.into_no_null_iter().filter(|l| *l == "a").count(), 6);
+
+        assert!(df
+            .sample_n(11, false, false, Some(0), Some("label"))
+            .is_err());
+        assert!(df.sample_n(2, false, false, Some(0), Some("foo")).is_err());
+        Ok(())
+    }
 }
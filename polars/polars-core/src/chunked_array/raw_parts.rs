@@ -0,0 +1,224 @@
+use std::any::Any;
+use std::os::raw::c_void;
+
+use arrow::bitmap::utils::count_zeros;
+use arrow::ffi;
+
+use crate::prelude::*;
+
+/// Called once polars no longer refers to the foreign memory of a
+/// [`ChunkedArray::from_raw_parts`] array.
+pub type ReleaseCallback = Box<dyn FnOnce() + Send>;
+
+/// The `ArrowArray` struct of the Arrow C data interface. [`ffi::ArrowArray`] is
+/// ABI-compatible with it, but its fields are not public, so a producer fills it in
+/// through this definition, like a C producer would.
+#[repr(C)]
+struct ForeignArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut ForeignArrowArray,
+    dictionary: *mut ForeignArrowArray,
+    release: Option<unsafe extern "C" fn(array: *mut ForeignArrowArray)>,
+    private_data: *mut c_void,
+}
+
+// guard against a mismatch with the definition of arrow
+const _: () = assert!(
+    std::mem::size_of::<ForeignArrowArray>() == std::mem::size_of::<ffi::ArrowArray>()
+        && std::mem::align_of::<ForeignArrowArray>() == std::mem::align_of::<ffi::ArrowArray>()
+);
+
+/// Owned by the imported array through `private_data`.
+struct PrivateData {
+    buffers: [*const c_void; 2],
+    release: Option<ReleaseCallback>,
+}
+
+/// Called by arrow when the last buffer referring to the foreign memory is dropped.
+unsafe extern "C" fn release_foreign(array: *mut ForeignArrowArray) {
+    if array.is_null() {
+        return;
+    }
+    let array = &mut *array;
+    let mut private = Box::from_raw(array.private_data as *mut PrivateData);
+    if let Some(release) = private.release.take() {
+        release()
+    }
+    array.release = None;
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Create a [`ChunkedArray`] that refers to `len` values at `ptr` without copying them.
+    ///
+    /// `nulls` is an optional validity bitmap given as a pointer and its length in bytes.
+    /// A set bit marks a valid value, following the arrow format.
+    ///
+    /// `release` is called exactly once, when polars no longer refers to the memory: when the
+    /// returned array and all arrays that share its buffers are dropped, or before returning
+    /// if an error is returned. Use it to free the memory.
+    ///
+    /// # Errors
+    /// If `ptr` is null or not aligned to `T::Native`, or if the validity bitmap is too short.
+    ///
+    /// # Safety
+    /// - `ptr` must point to `len` initialized values.
+    /// - The validity pointer must point to the given number of initialized bytes.
+    /// - The memory must stay valid and must not be mutated until `release` is called.
+    /// - `release` is called from the thread that drops the last reference to the memory.
+    pub unsafe fn from_raw_parts(
+        name: &str,
+        ptr: *const T::Native,
+        len: usize,
+        nulls: Option<(*const u8, usize)>,
+        release: Option<ReleaseCallback>,
+    ) -> PolarsResult<Self> {
+        let invalid = |msg: String, release: Option<ReleaseCallback>| {
+            if let Some(release) = release {
+                release()
+            }
+            Err(PolarsError::ComputeError(msg.into()))
+        };
+        if ptr.is_null() || ptr.align_offset(std::mem::align_of::<T::Native>()) != 0 {
+            return invalid(
+                format!(
+                    "pointer to the values is null or not aligned to {:?}",
+                    T::get_dtype()
+                ),
+                release,
+            );
+        }
+        let (validity, null_count) = match nulls {
+            Some((validity, n_bytes)) => {
+                if validity.is_null() || n_bytes * 8 < len {
+                    return invalid(
+                        format!("validity bitmap of {n_bytes} bytes is too short for {len} values"),
+                        release,
+                    );
+                }
+                let bytes = std::slice::from_raw_parts(validity, n_bytes);
+                (validity as *const c_void, count_zeros(bytes, 0, len))
+            }
+            None => (std::ptr::null(), 0),
+        };
+
+        let private = Box::into_raw(Box::new(PrivateData {
+            buffers: [validity, ptr as *const c_void],
+            release,
+        }));
+        let foreign = ForeignArrowArray {
+            length: len as i64,
+            null_count: null_count as i64,
+            offset: 0,
+            n_buffers: 2,
+            n_children: 0,
+            buffers: (*private).buffers.as_mut_ptr(),
+            children: std::ptr::null_mut(),
+            dictionary: std::ptr::null_mut(),
+            release: Some(release_foreign),
+            private_data: private as *mut c_void,
+        };
+        let mut array = ffi::ArrowArray::empty();
+        // Safety:
+        // `ffi::ArrowArray` is the C data interface struct that `ForeignArrowArray` defines.
+        // The empty array has no release callback, so nothing is leaked by overwriting it.
+        std::ptr::write(
+            &mut array as *mut ffi::ArrowArray as *mut ForeignArrowArray,
+            foreign,
+        );
+        // if the import fails, dropping `array` calls `release`
+        let arr = ffi::import_array_from_c(array, T::get_dtype().to_arrow())?;
+        Ok(Self::from_chunks(name, vec![arr]))
+    }
+
+    /// Give up the ownership of the values, returning a pointer to them, their length and
+    /// an owner that keeps the memory alive. The owner is the underlying
+    /// [`PrimitiveArray`], which can be downcast to access the validity.
+    ///
+    /// The array is rechunked first. The values may be shared with other arrays, so writing
+    /// through the pointer is only sound if the owner holds the only reference to them.
+    pub fn into_raw_parts(self) -> (*mut T::Native, usize, Arc<dyn Any>) {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap().clone();
+        let ptr = arr.values().as_ptr() as *mut T::Native;
+        let len = arr.len();
+        (ptr, len, Arc::new(arr))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_raw_parts_round_trip() -> PolarsResult<()> {
+        let values = vec![1i32, 2, 3, 4, 5];
+        // the fourth value is null
+        let validity = [0b0001_0111u8];
+        let released = Arc::new(AtomicUsize::new(0));
+        let callback = || -> Option<ReleaseCallback> {
+            let released = released.clone();
+            Some(Box::new(move || {
+                released.fetch_add(1, Ordering::SeqCst);
+            }))
+        };
+
+        let ca = unsafe {
+            Int32Chunked::from_raw_parts(
+                "a",
+                values.as_ptr(),
+                values.len(),
+                Some((validity.as_ptr(), 1)),
+                callback(),
+            )?
+        };
+        assert_eq!(Vec::from(&ca), &[Some(1), Some(2), Some(3), None, Some(5)]);
+        // zero copy
+        assert_eq!(
+            ca.downcast_iter().next().unwrap().values().as_ptr(),
+            values.as_ptr()
+        );
+        let sliced = ca.slice(1, 2);
+        drop(ca);
+        assert_eq!(released.load(Ordering::SeqCst), 0);
+        drop(sliced);
+        assert_eq!(released.load(Ordering::SeqCst), 1);
+
+        // the callback is also called on errors
+        let out = unsafe {
+            Int32Chunked::from_raw_parts(
+                "a",
+                values.as_ptr(),
+                9,
+                Some((validity.as_ptr(), 1)),
+                callback(),
+            )
+        };
+        assert!(out.is_err());
+        assert_eq!(released.load(Ordering::SeqCst), 2);
+        let out =
+            unsafe { Int32Chunked::from_raw_parts("a", std::ptr::null(), 0, None, callback()) };
+        assert!(out.is_err());
+        assert_eq!(released.load(Ordering::SeqCst), 3);
+
+        let ca = unsafe { Int32Chunked::from_raw_parts("a", values.as_ptr(), 3, None, None)? };
+        let (ptr, len, owner) = ca.into_raw_parts();
+        assert_eq!(len, 3);
+        assert_eq!(ptr as *const i32, values.as_ptr());
+        assert!(owner
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap()
+            .validity()
+            .is_none());
+        Ok(())
+    }
+}
@@ -31,9 +31,21 @@ impl DateChunked {
     }
 
     /// Format Date with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// # Panics
+    /// Panics if `fmt` is not a valid format string, see [`DateChunked::try_strftime`].
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
+        self.try_strftime(fmt).unwrap()
+    }
+
+    /// Format Date with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// The format string is validated and parsed once before the values are formatted.
+    /// Null values stay null. Returns an error if `fmt` is not a valid format string.
+    pub fn try_strftime(&self, fmt: &str) -> PolarsResult<Utf8Chunked> {
+        let items = compile_strftime(fmt)?;
         let date = NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
-        let fmted = format!("{}", date.format(fmt));
+        let fmted = format!("{}", date.format_with_items(items.iter()));
 
         let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
             let mut buf = String::new();
@@ -45,7 +57,7 @@ impl DateChunked {
                     None => mutarr.push_null(),
                     Some(v) => {
                         buf.clear();
-                        let datefmt = date32_to_date(*v).format(fmt);
+                        let datefmt = date32_to_date(*v).format_with_items(items.iter());
                         write!(buf, "{datefmt}").unwrap();
                         mutarr.push(Some(&buf))
                     }
@@ -56,7 +68,7 @@ impl DateChunked {
             Box::new(arr)
         });
         ca.rename(self.name());
-        ca
+        Ok(ca)
     }
 
     /// Construct a new [`DateChunked`] from an iterator over optional [`NaiveDate`].
@@ -5,7 +5,7 @@ use arrow::temporal_conversions::parse_offset;
 use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
-use chrono::format::{DelayedFormat, StrftimeItems};
+use chrono::format::{DelayedFormat, Item};
 #[cfg(feature = "timezones")]
 use chrono::FixedOffset;
 #[cfg(feature = "timezones")]
@@ -33,11 +33,11 @@ fn validate_time_zone(tz: TimeZone) -> PolarsResult<()> {
     }
 }
 
-fn apply_datefmt_f<'a>(
+fn apply_datefmt_f<'a: 'b, 'b>(
     arr: &PrimitiveArray<i64>,
-    fmted: &'a str,
+    fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
-    datefmt_f: impl Fn(NaiveDateTime) -> DelayedFormat<StrftimeItems<'a>>,
+    datefmt_f: impl Fn(NaiveDateTime) -> DelayedFormat<std::slice::Iter<'b, Item<'a>>>,
 ) -> ArrayRef {
     let mut buf = String::new();
     let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * fmted.len() + 1);
@@ -61,31 +61,31 @@ fn apply_datefmt_f<'a>(
 fn format_fixed_offset(
     tz: FixedOffset,
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    items: &[Item],
     fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format(fmt);
+    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format_with_items(items.iter());
     apply_datefmt_f(arr, fmted, conversion_f, datefmt_f)
 }
 #[cfg(feature = "timezones")]
 fn format_tz(
     tz: Tz,
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    items: &[Item],
     fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format(fmt);
+    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format_with_items(items.iter());
     apply_datefmt_f(arr, fmted, conversion_f, datefmt_f)
 }
 fn format_naive(
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    items: &[Item],
     fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt: NaiveDateTime| ndt.format(fmt);
+    let datefmt_f = |ndt: NaiveDateTime| ndt.format_with_items(items.iter());
     apply_datefmt_f(arr, fmted, conversion_f, datefmt_f)
 }
 
@@ -194,7 +194,19 @@ impl DatetimeChunked {
     }
 
     /// Format Datetime with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// # Panics
+    /// Panics if `fmt` is not a valid format string, see [`DatetimeChunked::try_strftime`].
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
+        self.try_strftime(fmt).unwrap()
+    }
+
+    /// Format Datetime with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// The format string is validated and parsed once before the values are formatted.
+    /// Null values stay null. Returns an error if `fmt` is not a valid format string.
+    pub fn try_strftime(&self, fmt: &str) -> PolarsResult<Utf8Chunked> {
+        let items = compile_strftime(fmt)?;
         #[cfg(feature = "timezones")]
         use chrono::Utc;
         let conversion_f = match self.time_unit() {
@@ -211,28 +223,31 @@ impl DatetimeChunked {
             #[cfg(feature = "timezones")]
             Some(_) => format!(
                 "{}",
-                Utc.from_local_datetime(&dt).earliest().unwrap().format(fmt)
+                Utc.from_local_datetime(&dt)
+                    .earliest()
+                    .unwrap()
+                    .format_with_items(items.iter())
             ),
-            _ => format!("{}", dt.format(fmt)),
+            _ => format!("{}", dt.format_with_items(items.iter())),
         };
 
         let mut ca: Utf8Chunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
             Some(time_zone) => match parse_offset(time_zone) {
                 Ok(time_zone) => self.apply_kernel_cast(&|arr| {
-                    format_fixed_offset(time_zone, arr, fmt, &fmted, conversion_f)
+                    format_fixed_offset(time_zone, arr, &items, &fmted, conversion_f)
                 }),
                 Err(_) => match time_zone.parse::<Tz>() {
                     Ok(time_zone) => self.apply_kernel_cast(&|arr| {
-                        format_tz(time_zone, arr, fmt, &fmted, conversion_f)
+                        format_tz(time_zone, arr, &items, &fmted, conversion_f)
                     }),
                     Err(_) => unreachable!(),
                 },
             },
-            _ => self.apply_kernel_cast(&|arr| format_naive(arr, fmt, &fmted, conversion_f)),
+            _ => self.apply_kernel_cast(&|arr| format_naive(arr, &items, &fmted, conversion_f)),
         };
         ca.rename(self.name());
-        ca
+        Ok(ca)
     }
 
     /// Construct a new [`DatetimeChunked`] from an iterator over [`NaiveDateTime`].
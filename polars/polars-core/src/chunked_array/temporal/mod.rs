@@ -9,6 +9,8 @@ mod duration;
 #[cfg(feature = "dtype-time")]
 mod time;
 
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+use chrono::format::{Item, StrftimeItems};
 #[cfg(feature = "dtype-date")]
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
@@ -16,7 +18,22 @@ use chrono::NaiveDateTime;
 use chrono::NaiveTime;
 
 pub use self::conversion::*;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+use crate::prelude::*;
 
 pub fn unix_time() -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(0, 0).unwrap()
 }
+
+/// Parse a `strftime` format string once, so that the parsed items can be reused to
+/// format every value. Returns an error if the format string is invalid.
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+pub(crate) fn compile_strftime(fmt: &str) -> PolarsResult<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(fmt).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(PolarsError::ComputeError(
+            format!("invalid format string: '{fmt}'").into(),
+        ));
+    }
+    Ok(items)
+}
@@ -6,7 +6,7 @@ use polars_arrow::trusted_len::{FromIteratorReversed, PushUnchecked};
 
 use crate::chunked_array::upstream_traits::PolarsAsRef;
 use crate::prelude::*;
-use crate::utils::{CustomIterTools, FromTrustedLenIterator, NoNull};
+use crate::utils::{CustomIterTools, FromTrustedLenIterator, NoNull, TrustMyLength};
 
 impl<T> FromTrustedLenIterator<Option<T::Native>> for ChunkedArray<T>
 where
@@ -22,6 +22,27 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Create a new [`ChunkedArray`] from an iterator of which the length is known
+    /// upfront. This always takes the fast path that preallocates `length` values.
+    ///
+    /// # Safety
+    /// The iterator must yield exactly `length` items.
+    pub unsafe fn from_trusted_len_iter<I>(name: &str, iter: I, length: usize) -> Self
+    where
+        I: Iterator<Item = Option<T::Native>>,
+    {
+        let iter = TrustMyLength::new(iter, length);
+        let arr =
+            PrimitiveArray::from_trusted_len_iter_unchecked(iter).to(T::get_dtype().to_arrow());
+        debug_assert_eq!(arr.len(), length);
+        ChunkedArray::from_chunks(name, vec![Box::new(arr)])
+    }
+}
+
 // NoNull is only a wrapper needed for specialization
 impl<T> FromTrustedLenIterator<T::Native> for NoNull<ChunkedArray<T>>
 where
@@ -252,4 +273,12 @@ mod test {
             .collect_reversed();
         assert_eq!(Vec::from(&ca), &[Some(4), None, Some(2), None, Some(0)]);
     }
+
+    #[test]
+    fn test_from_trusted_len_iter() {
+        let iter = (0..4).map(|v| if v == 1 { None } else { Some(v) });
+        let ca = unsafe { Int32Chunked::from_trusted_len_iter("a", iter, 4) };
+        assert_eq!(ca.name(), "a");
+        assert_eq!(Vec::from(&ca), &[Some(0), None, Some(2), Some(3)]);
+    }
 }
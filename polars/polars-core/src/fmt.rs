@@ -590,6 +590,96 @@ impl Display for DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Write the `DataFrame` as a GitHub flavored Markdown table. Numeric columns are
+    /// right aligned and all other columns are left aligned. At most `max_rows` rows are
+    /// written; if the `DataFrame` is taller, a row of `...` marks the omitted rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("name" => &["a", "bc"], "value" => &[1, 100])?;
+    /// let mut buf = Vec::new();
+    /// df.to_markdown(&mut buf, 10)?;
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(buf).unwrap(),
+    ///     "| name | value |\n| :--- | ----: |\n| a    |     1 |\n| bc   |   100 |\n"
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_markdown<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        max_rows: usize,
+    ) -> PolarsResult<()> {
+        let n_rows = std::cmp::min(self.height(), max_rows);
+        let truncated = n_rows < self.height();
+        let escape = |v: &str| v.replace('|', "\\|");
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|s| {
+                let mut cells = Vec::with_capacity(n_rows + 1 + truncated as usize);
+                cells.push(escape(s.name()));
+                for i in 0..n_rows {
+                    cells.push(escape(&s.str_value(i)?));
+                }
+                if truncated {
+                    cells.push("...".to_string());
+                }
+                Ok(cells)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let widths = columns
+            .iter()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|v| v.chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect::<Vec<_>>();
+        let right_aligned = self
+            .columns
+            .iter()
+            .map(|s| s.dtype().is_numeric())
+            .collect::<Vec<_>>();
+
+        let write_row = |writer: &mut W, row: usize| -> PolarsResult<()> {
+            for ((cells, &width), &right) in columns.iter().zip(&widths).zip(&right_aligned) {
+                let v = &cells[row];
+                if right {
+                    write!(writer, "| {v:>width$} ")?;
+                } else {
+                    write!(writer, "| {v:<width$} ")?;
+                }
+            }
+            writeln!(writer, "|")?;
+            Ok(())
+        };
+
+        write_row(writer, 0)?;
+        for (&width, &right) in widths.iter().zip(&right_aligned) {
+            let dashes = "-".repeat(width - 1);
+            if right {
+                write!(writer, "| {dashes}: ")?;
+            } else {
+                write!(writer, "| :{dashes} ")?;
+            }
+        }
+        writeln!(writer, "|")?;
+        for row in 1..=n_rows + truncated as usize {
+            write_row(writer, row)?;
+        }
+        Ok(())
+    }
+}
+
 fn fmt_integer<T: Num + NumCast + Display>(
     f: &mut Formatter<'_>,
     width: usize,
@@ -956,6 +1046,25 @@ impl<T: PolarsObject> FmtList for ObjectChunked<T> {
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_to_markdown() -> PolarsResult<()> {
+        let df = df!(
+            "a|b" => &[Some("x"), None, Some("yy|z")],
+            "n" => &[1.5, 10.0, 2.0]
+        )?;
+        let mut buf = Vec::new();
+        df.to_markdown(&mut buf, 2)?;
+        let expected = "\
+| a\\|b |    n |
+| :--- | ---: |
+| x    |  1.5 |
+| null | 10.0 |
+| ...  |  ... |
+";
+        assert_eq!(String::from_utf8(buf).unwrap(), expected);
+        Ok(())
+    }
+
     #[test]
     fn test_fmt_list() {
         let mut builder =
@@ -14,6 +14,7 @@ use crate::frame::hash_join::_check_categorical_src;
 use crate::frame::hash_join::{
     create_probe_table, get_hash_tbl_threaded_join_partitioned, multiple_keys as mk, prepare_strs,
 };
+use crate::series::IsSorted;
 use crate::utils::{split_ca, split_df};
 use crate::vector_hasher::{df_rows_to_hashes_threaded, AsU64};
 use crate::POOL;
@@ -730,6 +731,42 @@ impl DataFrame {
             other, left_on, right_on, left_by, right_by, strategy, tolerance, None,
         )
     }
+
+    /// Asof join `other` on the nearest key within the groups of equal `by` values;
+    /// see [`DataFrame::join_asof_by`].
+    ///
+    /// The DataFrames don't have to be sorted by their join key; unsorted inputs are
+    /// sorted first and the result is returned in the row order of `self`.
+    pub fn asof_join_by(
+        &self,
+        other: &DataFrame,
+        by: &[&str],
+        left_on: &str,
+        right_on: &str,
+        strategy: AsofStrategy,
+    ) -> PolarsResult<DataFrame> {
+        let sort_idx = |df: &DataFrame, on: &str| -> PolarsResult<Option<IdxCa>> {
+            let s = df.column(on)?;
+            Ok(match s.is_sorted_flag() {
+                IsSorted::Ascending => None,
+                _ => Some(s.argsort(SortOptions::default())),
+            })
+        };
+
+        let other = match sort_idx(other, right_on)? {
+            Some(idx) => Cow::Owned(other.take(&idx)?),
+            None => Cow::Borrowed(other),
+        };
+        match sort_idx(self, left_on)? {
+            Some(idx) => {
+                let left = self.take(&idx)?;
+                let out = left.join_asof_by(&other, left_on, right_on, by, by, strategy, None)?;
+                // restore the original row order
+                out.take(&idx.argsort(SortOptions::default()))
+            }
+            None => self.join_asof_by(&other, left_on, right_on, by, by, strategy, None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -760,6 +797,31 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_asof_join_by_unsorted() -> PolarsResult<()> {
+        let a = df![
+            "time" => [3, 1, 4, 2],
+            "ticker" => ["x", "y", "x", "y"]
+        ]?;
+        let b = df![
+            "time" => [2, 0, 3, 1],
+            "ticker" => ["x", "y", "y", "x"],
+            "right_vals" => [1, 2, 3, 4]
+        ]?;
+
+        let out = a.asof_join_by(&b, &["ticker"], "time", "time", AsofStrategy::Backward)?;
+        assert_eq!(out.get_column_names(), &["time", "ticker", "right_vals"]);
+        assert_eq!(
+            Vec::from(out.column("time")?.i32()?),
+            &[Some(3), Some(1), Some(4), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("right_vals")?.i32()?),
+            &[Some(1), Some(2), Some(1), Some(2)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_asof_by2() -> PolarsResult<()> {
         let trades = df![
@@ -90,7 +90,7 @@ where
                 Some(tolerance) => {
                     let tolerance = tolerance.extract::<T::Native>().unwrap();
                     join_asof_backward_with_tolerance(
-                        self.cont_slice().unwrap(),
+                        ca.cont_slice().unwrap(),
                         other.cont_slice().unwrap(),
                         tolerance,
                     )
@@ -202,4 +202,141 @@ impl DataFrame {
     ) -> PolarsResult<DataFrame> {
         self._join_asof(other, left_on, right_on, strategy, tolerance, suffix, None)
     }
+
+    /// Asof join `other` on the nearest key; see [`DataFrame::join_asof`].
+    ///
+    /// Both DataFrames must be sorted by their join key. Duplicate column names
+    /// of `other` get the default `"_right"` suffix.
+    pub fn asof_join(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        tolerance: Option<AnyValue<'static>>,
+        strategy: AsofStrategy,
+    ) -> PolarsResult<DataFrame> {
+        self._join_asof(other, left_on, right_on, strategy, tolerance, None, None)
+    }
+
+    /// Asof join `other` on the nearest key, but only accept matches where
+    /// `|left_key - right_key| <= tolerance`.
+    ///
+    /// Rows without a match within `tolerance` get null values in the columns of `other`.
+    pub fn asof_join_with_tolerance(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        tolerance: AnyValue<'static>,
+        strategy: AsofStrategy,
+    ) -> PolarsResult<DataFrame> {
+        if tolerance.extract::<f64>().is_none() {
+            return Err(PolarsError::ComputeError(
+                format!("tolerance of an asof join must be numeric, got: {tolerance:?}").into(),
+            ));
+        }
+        self._join_asof(
+            other,
+            left_on,
+            right_on,
+            strategy,
+            Some(tolerance),
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_asof_join() -> PolarsResult<()> {
+        let mut left = df![
+            "time" => [1i64, 5, 10],
+            "left_val" => ["a", "b", "c"],
+        ]?;
+        // multiple chunks in the left key
+        left.vstack_mut(&df![
+            "time" => [12i64],
+            "left_val" => ["d"],
+        ]?)?;
+        let right = df![
+            "time" => [1i64, 2, 3, 6, 7],
+            "right_val" => [1i32, 2, 3, 6, 7],
+        ]?;
+
+        let out = left.asof_join(&right, "time", "time", None, AsofStrategy::Backward)?;
+        assert_eq!(
+            Vec::from(out.column("right_val")?.i32()?),
+            &[Some(1), Some(3), Some(7), Some(7)]
+        );
+
+        let out = left.asof_join(&right, "time", "time", None, AsofStrategy::Forward)?;
+        assert_eq!(
+            Vec::from(out.column("right_val")?.i32()?),
+            &[Some(1), Some(6), None, None]
+        );
+
+        let out = left.asof_join(
+            &right,
+            "time",
+            "time",
+            Some(AnyValue::Int64(2)),
+            AsofStrategy::Backward,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("right_val")?.i32()?),
+            &[Some(1), Some(3), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_asof_join_with_tolerance() -> PolarsResult<()> {
+        let left = df![
+            "time" => [1i64, 5, 10, 12],
+            "left_val" => ["a", "b", "c", "d"],
+        ]?;
+        let right = df![
+            "time" => [1i64, 2, 3, 6, 7],
+            "right_val" => [1i32, 2, 3, 6, 7],
+        ]?;
+
+        let out = left.asof_join_with_tolerance(
+            &right,
+            "time",
+            "time",
+            AnyValue::Int64(3),
+            AsofStrategy::Backward,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("right_val")?.i32()?),
+            &[Some(1), Some(3), Some(7), None]
+        );
+
+        let out = left.asof_join_with_tolerance(
+            &right,
+            "time",
+            "time",
+            AnyValue::Int64(1),
+            AsofStrategy::Forward,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("right_val")?.i32()?),
+            &[Some(1), Some(6), None, None]
+        );
+
+        assert!(left
+            .asof_join_with_tolerance(
+                &right,
+                "time",
+                "time",
+                AnyValue::Null,
+                AsofStrategy::Backward
+            )
+            .is_err());
+        Ok(())
+    }
 }
@@ -222,6 +222,42 @@ where
         Ok(out)
     }
 }
+/// Distinct keys with the indices where they occur.
+pub type GroupIndices<K> = Vec<(K, Vec<IdxSize>)>;
+
+/// Collect the groups as `(first index, indices)` pairs, ordered by first appearance.
+pub(crate) fn group_indices_ordered(groups: &GroupsProxy) -> GroupIndices<IdxSize> {
+    groups
+        .iter()
+        .map(|g| match g {
+            GroupsIndicator::Idx((first, idx)) => (first, idx.clone()),
+            GroupsIndicator::Slice([first, len]) => (first, (first..first + len).collect()),
+        })
+        .collect()
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    /// Map every distinct value to the indices where it occurs, ordered by first appearance.
+    ///
+    /// If `include_nulls` is `true`, the null values form a group with key `None`,
+    /// otherwise they are skipped.
+    pub fn group_indices(
+        &self,
+        include_nulls: bool,
+    ) -> PolarsResult<GroupIndices<Option<T::Native>>> {
+        let groups = self.group_tuples(false, true)?;
+        Ok(group_indices_ordered(&groups)
+            .into_iter()
+            .map(|(first, idx)| (self.get(first as usize), idx))
+            .filter(|(key, _)| include_nulls || key.is_some())
+            .collect())
+    }
+}
+
 impl IntoGroupsProxy for BooleanChunked {
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
         #[cfg(feature = "performant")]
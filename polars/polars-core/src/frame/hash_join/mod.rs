@@ -527,34 +527,34 @@ impl DataFrame {
         let join_column_index = self.iter().position(|s| s.name() == s_left.name()).unwrap();
 
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
-        let mut opt_join_tuples = &*opt_join_tuples;
+        let (mut left_idx, mut right_idx) = s_left.hash_join_outer_indices(s_right);
 
         if let Some((offset, len)) = slice {
-            opt_join_tuples = slice_slice(opt_join_tuples, offset, len);
+            left_idx = left_idx.slice(offset, len);
+            right_idx = right_idx.slice(offset, len);
         }
 
-        // Take the left and right dataframes by join tuples
+        // Take the left and right dataframes by the join indices, a null index gives nulls.
+        // Safety:
+        // the join indices are in bounds
         let (mut df_left, df_right) = POOL.join(
+            || unsafe { self.drop(s_left.name()).unwrap().take_unchecked(&left_idx) },
             || unsafe {
-                self.drop(s_left.name()).unwrap().take_opt_iter_unchecked(
-                    opt_join_tuples
-                        .iter()
-                        .map(|(left, _right)| left.map(|i| i as usize)),
-                )
-            },
-            || unsafe {
-                other.drop(s_right.name()).unwrap().take_opt_iter_unchecked(
-                    opt_join_tuples
-                        .iter()
-                        .map(|(_left, right)| right.map(|i| i as usize)),
-                )
+                other
+                    .drop(s_right.name())
+                    .unwrap()
+                    .take_unchecked(&right_idx)
             },
         );
 
+        // the key column takes the key of whichever side matched
+        let opt_join_tuples = left_idx
+            .into_iter()
+            .zip(right_idx.into_iter())
+            .collect::<Vec<_>>();
         let mut s = s_left
             .to_physical_repr()
-            .zip_outer_join_column(&s_right.to_physical_repr(), opt_join_tuples);
+            .zip_outer_join_column(&s_right.to_physical_repr(), &opt_join_tuples);
         s.rename(s_left.name());
         let s = match s_left.dtype() {
             #[cfg(feature = "dtype-categorical")]
@@ -579,4 +579,30 @@ impl DataFrame {
         df_left.get_columns_mut().insert(join_column_index, s);
         _finish_join(df_left, df_right, suffix.as_deref())
     }
+
+    /// Join `left` and `right` on precomputed join indices, e.g. produced by a custom join
+    /// algorithm. Row `i` of the output combines row `left_idx[i]` of `left` with row
+    /// `right_idx[i]` of `right`; a null index gives a row of nulls on that side.
+    ///
+    /// Duplicate column names of `right` get the `"_right"` suffix. Returns an error if the
+    /// index arrays differ in length or contain an out of bounds index.
+    pub fn join_on_index(
+        left: &DataFrame,
+        right: &DataFrame,
+        left_idx: &IdxCa,
+        right_idx: &IdxCa,
+    ) -> PolarsResult<DataFrame> {
+        if left_idx.len() != right_idx.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "join index arrays must have equal length, got {} and {}",
+                    left_idx.len(),
+                    right_idx.len()
+                )
+                .into(),
+            ));
+        }
+        let (df_left, df_right) = POOL.join(|| left.take(left_idx), || right.take(right_idx));
+        _finish_join(df_left?, df_right?, None)
+    }
 }
@@ -131,6 +131,59 @@ impl Series {
     }
 }
 
+impl Series {
+    /// The join tuples of [`Series::hash_join_outer`] as a left and a right index array.
+    pub(super) fn hash_join_outer_indices(&self, other: &Series) -> (IdxCa, IdxCa) {
+        let join_tuples = self.hash_join_outer(other);
+        let mut left_idx = join_tuples.iter().map(|(l, _)| *l).collect::<IdxCa>();
+        let mut right_idx = join_tuples.iter().map(|(_, r)| *r).collect::<IdxCa>();
+        left_idx.rename(self.name());
+        right_idx.rename(other.name());
+        (left_idx, right_idx)
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: IntoSeries,
+{
+    /// Compute the row indices of an inner join of `left` and `right` on their values.
+    ///
+    /// Row `i` of the join combines row `left_idx[i]` of `left` with row `right_idx[i]`
+    /// of `right`, see [`DataFrame::join_on_index`].
+    pub fn inner_join_indices(left: &Self, right: &Self) -> (IdxCa, IdxCa) {
+        let (left, right) = (left.clone().into_series(), right.clone().into_series());
+        let ((left_idx, right_idx), _) = left.hash_join_inner(&right);
+        (
+            IdxCa::from_vec(left.name(), left_idx),
+            IdxCa::from_vec(right.name(), right_idx),
+        )
+    }
+
+    /// Compute the row indices of a left join of `left` and `right` on their values.
+    /// The right index is null for rows of `left` without a match.
+    pub fn left_join_indices(left: &Self, right: &Self) -> (IdxCa, IdxCa) {
+        // single chunks give flat indices instead of chunk ids
+        let left = left.clone().into_series().rechunk();
+        let right = right.clone().into_series().rechunk();
+        let (left_idx, right_idx) = left.hash_join_left(&right);
+        #[cfg(feature = "chunked_ids")]
+        let (left_idx, right_idx) = (left_idx.left().unwrap(), right_idx.left().unwrap());
+        let mut right_idx = right_idx.into_iter().collect::<IdxCa>();
+        right_idx.rename(right.name());
+        (IdxCa::from_vec(left.name(), left_idx), right_idx)
+    }
+
+    /// Compute the row indices of a full outer join of `left` and `right` on their values.
+    /// An index is null where a row of the other side has no match.
+    ///
+    /// This is what the outer join of [`DataFrame`]s on a single key uses.
+    pub fn outer_join_indices(left: &Self, right: &Self) -> (IdxCa, IdxCa) {
+        let (left, right) = (left.clone().into_series(), right.clone().into_series());
+        left.hash_join_outer_indices(&right)
+    }
+}
+
 fn splitted_to_slice<T>(splitted: &[ChunkedArray<T>]) -> Vec<&[T::Native]>
 where
     T: PolarsNumericType,
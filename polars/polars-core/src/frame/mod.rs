@@ -54,6 +54,16 @@ pub enum UniqueKeepStrategy {
     None,
 }
 
+/// How [`DataFrame::select_by_pattern`] matches column names.
+#[cfg(any(feature = "strings", feature = "temporal"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatchType {
+    /// A glob pattern where `*` matches any sequence of characters and `?` a single character.
+    Glob,
+    /// A regular expression that must match the whole column name.
+    Regex,
+}
+
 /// A contiguous growable collection of `Series` that have the same length.
 ///
 /// ## Use declarations
@@ -586,6 +596,65 @@ impl DataFrame {
         Ok(())
     }
 
+    /// Get a new [`DataFrame`] where every column is renamed by `f`. The data is not copied.
+    ///
+    /// Returns an error if `f` produces duplicate column names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("Name" => &["a", "b"], "ID" => &[1, 2])?;
+    /// let df = df.map_column_names(|name| name.to_lowercase())?;
+    ///
+    /// assert_eq!(df.get_column_names(), &["name", "id"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn map_column_names<F>(&self, f: F) -> PolarsResult<DataFrame>
+    where
+        F: Fn(&str) -> String,
+    {
+        let names = self.columns.iter().map(|s| f(s.name())).collect::<Vec<_>>();
+        let mut df = self.clone();
+        df.set_column_names(&names)?;
+        Ok(df)
+    }
+
+    /// Get a new [`DataFrame`] where `prefix` is prepended to every column name, e.g. to
+    /// disambiguate the columns of two frames before a join.
+    ///
+    /// Returns an error if `prefix` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => &[1, 2], "b" => &[3, 4])?;
+    ///
+    /// assert_eq!(df.add_prefix("left_")?.get_column_names(), &["left_a", "left_b"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn add_prefix(&self, prefix: &str) -> PolarsResult<DataFrame> {
+        if prefix.is_empty() {
+            return Err(PolarsError::ComputeError(
+                "'prefix' in 'add_prefix' must not be empty".into(),
+            ));
+        }
+        self.map_column_names(|name| format!("{prefix}{name}"))
+    }
+
+    /// Get a new [`DataFrame`] where `suffix` is appended to every column name.
+    ///
+    /// Returns an error if `suffix` is empty.
+    pub fn add_suffix(&self, suffix: &str) -> PolarsResult<DataFrame> {
+        if suffix.is_empty() {
+            return Err(PolarsError::ComputeError(
+                "'suffix' in 'add_suffix' must not be empty".into(),
+            ));
+        }
+        self.map_column_names(|name| format!("{name}{suffix}"))
+    }
+
     /// Get the data types of the columns in the DataFrame.
     ///
     /// # Example
@@ -843,6 +912,15 @@ impl DataFrame {
         Ok(df)
     }
 
+    /// Stack `dfs` vertically, where the schema of the result is the union of the schemas of
+    /// `dfs`. Columns that are missing in a DataFrame are filled with nulls.
+    ///
+    /// Returns an error if a column name occurs with different data types.
+    #[cfg(feature = "diagonal_concat")]
+    pub fn stack_diagonal(dfs: &[&DataFrame]) -> PolarsResult<DataFrame> {
+        crate::functions::diag_concat_impl(dfs, true)
+    }
+
     /// Concatenate a DataFrame to this DataFrame
     ///
     /// If many `vstack` operations are done, it is recommended to call [`DataFrame::rechunk`].
@@ -1138,6 +1216,45 @@ impl DataFrame {
         inner(self, series)
     }
 
+    /// Insert a column before the column currently at `idx`, shifting the subsequent
+    /// columns to the right. If `idx` equals the width, the column is appended.
+    ///
+    /// If the column at `idx` has the same name, it is replaced. A column with the same
+    /// name at a different position is an error.
+    pub fn with_column_at<S: IntoSeries>(
+        &mut self,
+        idx: usize,
+        column: S,
+    ) -> PolarsResult<&mut Self> {
+        let series = column.into_series();
+        if idx > self.width() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "Column index: {} outside of DataFrame with {} columns",
+                    idx,
+                    self.width()
+                )
+                .into(),
+            ));
+        }
+        match self.find_idx_by_name(series.name()) {
+            Some(existing) if existing == idx => self.replace_at_idx(idx, series),
+            Some(existing) => Err(PolarsError::Duplicate(
+                format!(
+                    "column with name: '{}' already present in DataFrame at index {}",
+                    series.name(),
+                    existing
+                )
+                .into(),
+            )),
+            None if self.columns.is_empty() => {
+                self.columns.push(series);
+                Ok(self)
+            }
+            None => self.insert_at_idx_no_name_check(idx, series),
+        }
+    }
+
     fn add_column_by_schema(&mut self, s: Series, schema: &Schema) -> PolarsResult<()> {
         let name = s.name();
         if let Some((idx, _, _)) = schema.get_full(name) {
@@ -1432,6 +1549,74 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(selected))
     }
 
+    /// Select the columns whose dtype is in `include` and not in `exclude` and return a new
+    /// `DataFrame`. If `include` is empty, all columns that are not excluded are selected.
+    /// The data is not copied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => &[1, 2], "b" => &["x", "y"], "c" => &[0.5, 1.5])?;
+    /// let numeric = df.select_dtypes(&[DataType::Int32, DataType::Float64], &[])?;
+    ///
+    /// assert_eq!(numeric.get_column_names(), &["a", "c"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn select_dtypes(&self, include: &[DataType], exclude: &[DataType]) -> PolarsResult<Self> {
+        let selected = self
+            .columns
+            .iter()
+            .filter(|s| {
+                let dtype = s.dtype();
+                (include.is_empty() || include.contains(dtype)) && !exclude.contains(dtype)
+            })
+            .cloned()
+            .collect();
+        Ok(DataFrame::new_no_checks(selected))
+    }
+
+    /// Select the columns whose name matches `pattern` and return a new `DataFrame`.
+    /// The pattern must match the whole name. The data is not copied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("x_1" => &[1, 2], "y_1" => &[3, 4], "x_2" => &[5, 6])?;
+    /// let xs = df.select_by_pattern("x_*", MatchType::Glob)?;
+    ///
+    /// assert_eq!(xs.get_column_names(), &["x_1", "x_2"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[cfg(any(feature = "strings", feature = "temporal"))]
+    pub fn select_by_pattern(&self, pattern: &str, match_type: MatchType) -> PolarsResult<Self> {
+        let pattern = match match_type {
+            MatchType::Glob => {
+                let mut buf = String::with_capacity(pattern.len() + 2);
+                buf.push('^');
+                for c in pattern.chars() {
+                    match c {
+                        '*' => buf.push_str(".*"),
+                        '?' => buf.push('.'),
+                        c => buf.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+                    }
+                }
+                buf.push('$');
+                buf
+            }
+            MatchType::Regex => format!("^(?:{pattern})$"),
+        };
+        let re = regex::Regex::new(&pattern)?;
+        let selected = self
+            .columns
+            .iter()
+            .filter(|s| re.is_match(s.name()))
+            .cloned()
+            .collect();
+        Ok(DataFrame::new_no_checks(selected))
+    }
+
     fn select_check_duplicates(&self, cols: &[String]) -> PolarsResult<()> {
         let mut names = PlHashSet::with_capacity(cols.len());
         for name in cols {
@@ -2201,6 +2386,76 @@ impl DataFrame {
         self.try_apply_at_idx(idx, f)
     }
 
+    /// Slide a window of `window_size` rows over the `DataFrame` and apply `f` to every
+    /// window. `f` must return a [`Series`] of length 1; the results are collected into a
+    /// new column that is appended to the `DataFrame` under the name of the returned
+    /// [`Series`]. The first `window_size - 1` rows, which have no complete window, are
+    /// null.
+    ///
+    /// If the `DataFrame` is shorter than `window_size`, `f` is never called and a null
+    /// column named `"rolling_apply"` is appended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => &[1, 2, 3, 4],
+    ///                         "b" => &[10, 20, 30, 40])?;
+    /// let out = df.rolling_apply(2, |window| {
+    ///     let sum = (window.column("a")? * window.column("b")?).sum::<i32>();
+    ///     Ok(Series::new("dot", &[sum]))
+    /// })?;
+    ///
+    /// assert_eq!(
+    ///     Vec::from(out.column("dot")?.i32()?),
+    ///     &[None, Some(50), Some(130), Some(250)]
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rolling_apply<F>(&self, window_size: usize, f: F) -> PolarsResult<DataFrame>
+    where
+        F: Fn(&DataFrame) -> PolarsResult<Series>,
+    {
+        if window_size == 0 {
+            return Err(PolarsError::ComputeError(
+                "'window_size' in 'rolling_apply' must be positive".into(),
+            ));
+        }
+        let height = self.height();
+        let mut out: Option<Series> = None;
+        for end in window_size..=height {
+            let value = f(&self.slice((end - window_size) as i64, window_size))?;
+            if value.len() != 1 {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "function in 'rolling_apply' should return a Series of length 1, got length {}",
+                        value.len()
+                    )
+                    .into(),
+                ));
+            }
+            match &mut out {
+                Some(out) => {
+                    out.append(&value)?;
+                }
+                None => {
+                    let mut first = Series::full_null(value.name(), window_size - 1, value.dtype());
+                    first.append(&value)?;
+                    out = Some(first);
+                }
+            }
+        }
+        let out = match out {
+            Some(out) => out.rechunk(),
+            None => {
+                let mut out = Series::full_null("", height, &DataType::Null);
+                out.rename("rolling_apply");
+                out
+            }
+        };
+        self.hstack(&[out])
+    }
+
     /// Slice the `DataFrame` along the rows.
     ///
     /// # Example
@@ -2410,6 +2665,19 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
+    /// Replace the nulls in column `target` with the values of column `fill` at the same rows.
+    ///
+    /// The filled column gets the supertype of both columns. Returns an error if either
+    /// column doesn't exist or the columns have no common supertype.
+    pub fn fill_null_with_col(&self, target: &str, fill: &str) -> PolarsResult<Self> {
+        let target_s = self.column(target)?;
+        let fill_s = self.column(fill)?;
+        let filled = crate::functions::coalesce(&[target_s, fill_s])?;
+        let mut df = self.clone();
+        df.replace(target, filled)?;
+        Ok(df)
+    }
+
     /// Summary statistics for a DataFrame. Only summarizes numeric datatypes at the moment and returns nulls for non numeric datatypes.
     /// Try in keep output similar to pandas
     ///
@@ -2514,6 +2782,119 @@ impl DataFrame {
         Ok(summary)
     }
 
+    /// Cast the columns to `Float64`. Covariance and correlation are only defined for
+    /// numeric columns.
+    fn numeric_columns_f64(&self) -> PolarsResult<Vec<Float64Chunked>> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|s| {
+                if !s.dtype().is_numeric() {
+                    return Err(PolarsError::InvalidOperation(
+                        format!(
+                            "covariance and correlation are not supported for column '{}' of dtype {:?}",
+                            s.name(),
+                            s.dtype()
+                        )
+                        .into(),
+                    ));
+                }
+                let s = s.cast(&DataType::Float64)?;
+                Ok(s.f64().unwrap().clone())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if columns.len() < 2 {
+            return Err(PolarsError::ComputeError(
+                "covariance and correlation require at least 2 numeric columns".into(),
+            ));
+        }
+        Ok(columns)
+    }
+
+    /// Center `a` and `b` around their means over the rows where both are valid, and return
+    /// the sums of `a * b`, `a * a` and `b * b` and the number of those rows.
+    fn centered_pair_sums(a: &Float64Chunked, b: &Float64Chunked) -> (f64, f64, f64, usize) {
+        let pairs = a
+            .into_iter()
+            .zip(b)
+            .filter_map(|(a, b)| Some((a?, b?)))
+            .collect::<Vec<_>>();
+        let count = pairs.len();
+        let (sum_a, sum_b) = pairs
+            .iter()
+            .fold((0.0, 0.0), |(sum_a, sum_b), (a, b)| (sum_a + a, sum_b + b));
+        let (mean_a, mean_b) = (sum_a / count as f64, sum_b / count as f64);
+        let (sum_ab, sum_aa, sum_bb) =
+            pairs
+                .iter()
+                .fold((0.0, 0.0, 0.0), |(sum_ab, sum_aa, sum_bb), (a, b)| {
+                    let (a, b) = (a - mean_a, b - mean_b);
+                    (sum_ab + a * b, sum_aa + a * a, sum_bb + b * b)
+                });
+        (sum_ab, sum_aa, sum_bb, count)
+    }
+
+    /// Compute a symmetric matrix, in row major order, by applying `f` to the centered sums
+    /// of every pair of columns.
+    fn pairwise_matrix<F>(columns: &[Float64Chunked], f: F) -> Vec<Option<f64>>
+    where
+        F: Fn(usize, usize, (f64, f64, f64, usize)) -> Option<f64>,
+    {
+        let n = columns.len();
+        let mut matrix = vec![None; n * n];
+        for i in 0..n {
+            for j in i..n {
+                let value = f(i, j, Self::centered_pair_sums(&columns[i], &columns[j]));
+                matrix[i * n + j] = value;
+                matrix[j * n + i] = value;
+            }
+        }
+        matrix
+    }
+
+    fn square_frame(columns: &[Float64Chunked], matrix: &[Option<f64>]) -> DataFrame {
+        let out = columns
+            .iter()
+            .zip(matrix.chunks_exact(columns.len()))
+            .map(|(ca, values)| Float64Chunked::from_slice_options(ca.name(), values).into_series())
+            .collect();
+        DataFrame::new_no_checks(out)
+    }
+
+    /// Compute the pairwise covariance between the numeric columns, with `n - ddof` as
+    /// denominator.
+    ///
+    /// The result is a square `DataFrame` where the value at row `i` of column `j` is the
+    /// covariance between the `i`th and the `j`th column. For every pair of columns, only
+    /// the rows where both are valid are taken into account. Returns an error if a
+    /// column is not numeric.
+    pub fn cov(&self, ddof: u8) -> PolarsResult<DataFrame> {
+        let columns = self.numeric_columns_f64()?;
+        let matrix = Self::pairwise_matrix(&columns, |_, _, (sum_ab, _, _, count)| {
+            (count > ddof as usize).then(|| sum_ab / (count - ddof as usize) as f64)
+        });
+        Ok(Self::square_frame(&columns, &matrix))
+    }
+
+    /// Compute the pairwise pearson correlation between the numeric columns.
+    ///
+    /// The result is a square `DataFrame` where the value at row `i` of column `j` is the
+    /// correlation between the `i`th and the `j`th column. For every pair of columns, only
+    /// the rows where both are valid are taken into account. Returns an error if a
+    /// column is not numeric.
+    pub fn corr(&self, ddof: u8) -> PolarsResult<DataFrame> {
+        let columns = self.numeric_columns_f64()?;
+        let matrix = Self::pairwise_matrix(&columns, |i, j, (sum_ab, sum_aa, sum_bb, count)| {
+            if i == j {
+                Some(1.0)
+            } else {
+                // the `n - ddof` denominators of the covariance and the stds cancel out
+                (count > ddof as usize).then(|| sum_ab / (sum_aa * sum_bb).sqrt())
+            }
+        });
+        Ok(Self::square_frame(&columns, &matrix))
+    }
+
     /// Aggregate the columns to their maximum values.
     ///
     /// # Example
@@ -3624,6 +4005,155 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "diagonal_concat")]
+    fn test_stack_diagonal() -> PolarsResult<()> {
+        let a = df!("a" => [1, 2], "b" => ["x", "y"])?;
+        let b = df!("c" => [3.0], "a" => [3])?;
+        let out = DataFrame::stack_diagonal(&[&a, &b])?;
+        let expected = df!(
+            "a" => [1, 2, 3],
+            "b" => [Some("x"), Some("y"), None],
+            "c" => [None, None, Some(3.0)]
+        )?;
+        assert!(out.frame_equal_missing(&expected));
+
+        let c = df!("a" => ["z"])?;
+        assert!(DataFrame::stack_diagonal(&[&a, &c]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_column_names() -> PolarsResult<()> {
+        let df = df!(
+            "A" => [1, 2, 3],
+            "b" => [1, 2, 3]
+        )?;
+        let out = df.map_column_names(|name| format!("{name}_x"))?;
+        assert_eq!(out.get_column_names(), &["A_x", "b_x"]);
+        assert_eq!(df.get_column_names(), &["A", "b"]);
+        assert!(df.map_column_names(|_| "a".to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_prefix_suffix() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2],
+            "b" => [3, 4]
+        )?;
+        assert_eq!(df.add_prefix("x_")?.get_column_names(), &["x_a", "x_b"]);
+        assert_eq!(df.add_suffix("_y")?.get_column_names(), &["a_y", "b_y"]);
+        assert!(df.add_prefix("").is_err());
+        assert!(df.add_suffix("").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_apply() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2, 3, 4],
+            "b" => [4, 3, 2, 1]
+        )?;
+        let out = df.rolling_apply(3, |window| {
+            let spread = window.column("a")?.max::<i32>().unwrap()
+                - window.column("b")?.min::<i32>().unwrap();
+            Ok(Series::new("spread", &[spread]))
+        })?;
+        assert_eq!(out.get_column_names(), &["a", "b", "spread"]);
+        assert_eq!(
+            Vec::from(out.column("spread")?.i32()?),
+            &[None, None, Some(1), Some(3)]
+        );
+
+        let out = df.rolling_apply(5, |_| unreachable!())?;
+        assert_eq!(out.column("rolling_apply")?.null_count(), 4);
+
+        assert!(df.rolling_apply(0, |w| Ok(w.column("a")?.clone())).is_err());
+        assert!(df.rolling_apply(2, |w| Ok(w.column("a")?.clone())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_dtypes() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2],
+            "b" => ["x", "y"],
+            "c" => [1.0, 2.0],
+            "d" => [3i64, 4]
+        )?;
+        let out = df.select_dtypes(&[DataType::Int32, DataType::Int64], &[])?;
+        assert_eq!(out.get_column_names(), &["a", "d"]);
+        let out = df.select_dtypes(&[], &[DataType::Utf8])?;
+        assert_eq!(out.get_column_names(), &["a", "c", "d"]);
+        let out = df.select_dtypes(&[DataType::Int32, DataType::Utf8], &[DataType::Int32])?;
+        assert_eq!(out.get_column_names(), &["b"]);
+        assert_eq!(df.select_dtypes(&[DataType::Boolean], &[])?.width(), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "strings", feature = "temporal"))]
+    fn test_select_by_pattern() -> PolarsResult<()> {
+        let df = df!(
+            "a_1" => [1, 2],
+            "b_1" => [1, 2],
+            "a_2" => [1, 2],
+            "a.3" => [1, 2]
+        )?;
+        let out = df.select_by_pattern("a_*", MatchType::Glob)?;
+        assert_eq!(out.get_column_names(), &["a_1", "a_2"]);
+        let out = df.select_by_pattern("?_1", MatchType::Glob)?;
+        assert_eq!(out.get_column_names(), &["a_1", "b_1"]);
+        let out = df.select_by_pattern("a.3", MatchType::Glob)?;
+        assert_eq!(out.get_column_names(), &["a.3"]);
+        let out = df.select_by_pattern(r"a.\d", MatchType::Regex)?;
+        assert_eq!(out.get_column_names(), &["a_1", "a_2", "a.3"]);
+        let out = df.select_by_pattern("a|b", MatchType::Regex)?;
+        assert_eq!(out.width(), 0);
+        assert!(df.select_by_pattern("(", MatchType::Regex).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_with_col() -> PolarsResult<()> {
+        let df = df!(
+            "price" => [Some(1i32), None, None],
+            "default_price" => [Some(10i64), Some(20), None],
+            "name" => ["a", "b", "c"]
+        )?;
+        let out = df.fill_null_with_col("price", "default_price")?;
+        assert_eq!(out.get_column_names(), df.get_column_names());
+        assert_eq!(
+            Vec::from(out.column("price")?.i64()?),
+            &[Some(1), Some(20), None]
+        );
+        assert!(df.fill_null_with_col("price", "missing").is_err());
+        assert!(df.fill_null_with_col("missing", "price").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_column_at() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [1, 2, 3],
+            "b" => [1, 2, 3]
+        )?;
+
+        df.with_column_at(1, Series::new("c", [4, 5, 6]))?;
+        df.with_column_at(3, Series::new("d", [4, 5, 6]))?;
+        assert_eq!(df.get_column_names(), &["a", "c", "b", "d"]);
+
+        // same name at the same position replaces
+        df.with_column_at(1, Series::new("c", [7, 8, 9]))?;
+        assert_eq!(df.column("c")?.i32()?.get(0), Some(7));
+        assert_eq!(df.width(), 4);
+
+        assert!(df.with_column_at(0, Series::new("c", [1, 2, 3])).is_err());
+        assert!(df.with_column_at(5, Series::new("e", [1, 2, 3])).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_empty_df_hstack() -> PolarsResult<()> {
         let mut base = df!(
@@ -3669,4 +4199,103 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_df_corr() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2, 3, 4],
+            "b" => [2.0, 4.0, 6.0, 8.0],
+            "c" => [4, 3, 2, 1],
+            "d" => ["a", "b", "c", "d"]
+        )?;
+
+        assert!(df.corr(1).is_err());
+        let out = df.select(["a", "b", "c"])?.corr(1)?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        for (i, expected) in [[1.0, 1.0, -1.0], [1.0, 1.0, -1.0], [-1.0, -1.0, 1.0]]
+            .iter()
+            .enumerate()
+        {
+            let row = out.get_columns()[i].f64()?;
+            for (v, e) in row.into_no_null_iter().zip(expected) {
+                assert!((v - e).abs() < 1e-9);
+            }
+        }
+
+        assert!(df.select(["a", "d"])?.corr(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_df_corr_nulls() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [Some(1.0), Some(2.0), Some(3.0), None],
+            "b" => [1, 2, 3, 100],
+            "c" => [Some(2), None, Some(6), Some(4)]
+        )?;
+
+        // every pair is centered over the rows where both values are present
+        let r_bc = 4.0 / (57642.0 * 8.0 / 9.0f64).sqrt();
+        let expected = [[1.0, 1.0, 1.0], [1.0, 1.0, r_bc], [1.0, r_bc, 1.0]];
+        let out = df.corr(1)?;
+        for (i, expected) in expected.iter().enumerate() {
+            let row = out.get_columns()[i].f64()?;
+            for (v, e) in row.into_no_null_iter().zip(expected) {
+                assert!((v - e).abs() < 1e-9);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_df_cov() -> PolarsResult<()> {
+        // without nulls, all pairs use the same rows as the stds of the columns
+        let df = df!(
+            "a" => [1.0, 2.0, 4.0, 7.0, 5.0],
+            "b" => [2, 1, 5, 3, 4],
+            "c" => [9, 3, 2, 2, 0]
+        )?;
+
+        let cov = df.cov(1)?;
+        let corr = df.corr(1)?;
+        assert_eq!(cov.shape(), (3, 3));
+
+        let std = df
+            .get_columns()
+            .iter()
+            .map(|s| Ok(s.cast(&DataType::Float64)?.f64()?.std(1).unwrap()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        assert!((cov.column("b")?.f64()?.get(1).unwrap() - std[1] * std[1]).abs() < 1e-9);
+        for i in 0..3 {
+            for j in 0..3 {
+                let cov = cov.get_columns()[j].f64()?.get(i).unwrap();
+                let corr = corr.get_columns()[j].f64()?.get(i).unwrap();
+                assert!((cov / (std[i] * std[j]) - corr).abs() < 1e-9);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_df_cov_nulls() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [Some(1.0), Some(2.0), Some(3.0), None],
+            "b" => [1, 2, 3, 100],
+            "c" => [Some(2), None, Some(6), Some(4)]
+        )?;
+
+        // every pair is centered over the rows where both values are present
+        let expected = [[1.0, 1.0, 4.0], [1.0, 7205.0 / 3.0, 2.0], [4.0, 2.0, 4.0]];
+        let out = df.cov(1)?;
+        for (i, expected) in expected.iter().enumerate() {
+            let row = out.get_columns()[i].f64()?;
+            for (v, e) in row.into_no_null_iter().zip(expected) {
+                assert!((v - e).abs() < 1e-9);
+            }
+        }
+
+        let df = df!("a" => [1, 2], "b" => ["x", "y"], "c" => [3, 4])?;
+        assert!(df.cov(1).is_err());
+        Ok(())
+    }
 }
@@ -146,8 +146,8 @@ impl DataFrame {
                 let columns = self
                     .columns
                     .iter()
-                    .map(|s| s.cast(dtype).unwrap())
-                    .collect::<Vec<_>>();
+                    .map(|s| s.cast(dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
 
                 // this is very expensive. A lot of cache misses here.
                 // This is the part that is performance critical.
@@ -172,6 +172,10 @@ impl DataFrame {
     }
 
     /// Transpose a DataFrame. This is a very expensive operation.
+    ///
+    /// The result has `self.height()` columns named `column_{row index}` and `self.width()`
+    /// rows. The columns are cast to their common supertype, an error is returned if they
+    /// don't have one or cannot be cast to it.
     pub fn transpose(&self) -> PolarsResult<DataFrame> {
         let height = self.height();
         let width = self.width();
@@ -695,6 +699,11 @@ mod test {
 
         ]?;
         assert!(out.frame_equal_missing(&expected));
+
+        // cannot be cast to the supertype
+        let list = Series::new("a", &[Series::new("", &[1]), Series::new("", &[2])]);
+        let df = DataFrame::new(vec![list, Series::new("b", &[true, false])])?;
+        assert!(df.transpose().is_err());
         Ok(())
     }
 }
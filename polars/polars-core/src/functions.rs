@@ -209,6 +209,41 @@ pub fn concat_str(s: &[Series], delimiter: &str) -> PolarsResult<Utf8Chunked> {
     Ok(builder.finish())
 }
 
+/// Take element-wise the first non-null value of `arrays`, like SQL `COALESCE`.
+///
+/// The arrays are cast to their common supertype and must have equal lengths. Later
+/// arrays are only consulted while the result still contains nulls.
+pub fn coalesce(arrays: &[&Series]) -> PolarsResult<Series> {
+    let first = arrays.first().ok_or_else(|| {
+        PolarsError::ComputeError("cannot coalesce an empty list of arrays".into())
+    })?;
+    if let Some(s) = arrays.iter().find(|s| s.len() != first.len()) {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "arrays to coalesce must have equal length, got {} and {}",
+                first.len(),
+                s.len()
+            )
+            .into(),
+        ));
+    }
+    let mut dtype = first.dtype().clone();
+    for s in &arrays[1..] {
+        dtype = crate::utils::try_get_supertype(&dtype, s.dtype())?;
+    }
+
+    let mut out = first.cast(&dtype)?;
+    for s in &arrays[1..] {
+        if out.null_count() == 0 {
+            break;
+        }
+        let mask = out.is_not_null();
+        out = out.zip_with_same_type(&mask, &s.cast(&dtype)?)?;
+    }
+    out.rename(first.name());
+    Ok(out)
+}
+
 /// Concat `[DataFrame]`s horizontally.
 #[cfg(feature = "horizontal_concat")]
 /// Concat horizontally and extend with null values if lengths don't match
@@ -253,18 +288,37 @@ pub fn hor_concat_df(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
 #[cfg(feature = "diagonal_concat")]
 /// Concat diagonally thereby combining different schemas.
 pub fn diag_concat_df(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    let dfs = dfs.iter().collect::<Vec<_>>();
+    diag_concat_impl(&dfs, false)
+}
+
+/// Concat diagonally, if `check_dtypes` is set, columns with the same name but a different
+/// data type raise an error.
+#[cfg(feature = "diagonal_concat")]
+pub(crate) fn diag_concat_impl(dfs: &[&DataFrame], check_dtypes: bool) -> PolarsResult<DataFrame> {
     // TODO! replace with lazy only?
     let upper_bound_width = dfs.iter().map(|df| df.width()).sum();
     let mut column_names = AHashSet::with_capacity(upper_bound_width);
     let mut schema = Vec::with_capacity(upper_bound_width);
 
     for df in dfs {
-        df.get_columns().iter().for_each(|s| {
+        for s in df.get_columns() {
             let name = s.name();
             if column_names.insert(name) {
                 schema.push((name, s.dtype()))
+            } else if check_dtypes {
+                let (_, dtype) = schema.iter().find(|(other, _)| *other == name).unwrap();
+                if *dtype != s.dtype() {
+                    return Err(PolarsError::SchemaMisMatch(
+                        format!(
+                            "column '{name}' has conflicting data types {dtype:?} and {:?}",
+                            s.dtype()
+                        )
+                        .into(),
+                    ));
+                }
             }
-        });
+        }
     }
 
     let dfs = dfs
@@ -290,6 +344,22 @@ pub fn diag_concat_df(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_coalesce() -> PolarsResult<()> {
+        let a = Series::new("a", &[Some(1i32), None, None, None]);
+        let b = Series::new("b", &[Some(10i64), Some(20), None, None]);
+        let c = Series::new("c", &[Some(100i64), Some(200), Some(300), None]);
+
+        let out = coalesce(&[&a, &b, &c])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i64()?), &[Some(1), Some(20), Some(300), None]);
+        assert_eq!(Vec::from(coalesce(&[&a])?.i32()?), Vec::from(a.i32()?));
+
+        assert!(coalesce(&[]).is_err());
+        assert!(coalesce(&[&a, &a.slice(0, 2)]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_cov() {
         let a = Series::new("a", &[1.0f32, 2.0, 5.0]);
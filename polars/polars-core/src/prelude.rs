@@ -24,22 +24,27 @@ pub use crate::chunked_array::object::PolarsObject;
 pub use crate::chunked_array::ops::aggregate::*;
 #[cfg(feature = "rolling_window")]
 pub use crate::chunked_array::ops::rolling_window::RollingOptionsFixedWindow;
+pub use crate::chunked_array::ops::statistics::ColumnStatistics;
 #[cfg(feature = "rank")]
 pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 pub use crate::chunked_array::ops::*;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
-pub use crate::chunked_array::ChunkedArray;
 pub(crate) use crate::chunked_array::{to_array, ChunkIdIter};
+pub use crate::chunked_array::{ChunkedArray, ValidityMask};
 pub use crate::datatypes::*;
 pub use crate::error::{PolarsError, PolarsResult};
 #[cfg(feature = "asof_join")]
 pub use crate::frame::asof_join::*;
 pub use crate::frame::explode::MeltArgs;
 pub(crate) use crate::frame::groupby::aggregations::*;
-pub use crate::frame::groupby::{GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy};
+pub use crate::frame::groupby::{
+    GroupIndices, GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy,
+};
 pub use crate::frame::hash_join::JoinType;
 pub(crate) use crate::frame::hash_join::*;
+#[cfg(any(feature = "strings", feature = "temporal"))]
+pub use crate::frame::MatchType;
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
 pub use crate::schema::*;
@@ -0,0 +1,219 @@
+use serde::ser::Error;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::prelude::*;
+use crate::with_match_physical_numeric_polars_type;
+
+/// Serializes the values of a [`Series`] as a plain sequence, without name and dtype.
+struct JsonValues(Series);
+
+impl Serialize for JsonValues {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let s = &self.0;
+        match s.dtype() {
+            DataType::Boolean => serializer.collect_seq(s.bool().unwrap()),
+            DataType::Utf8 => serializer.collect_seq(s.utf8().unwrap()),
+            DataType::List(_) => serializer.collect_seq(
+                s.list()
+                    .unwrap()
+                    .into_iter()
+                    .map(|opt_s| opt_s.map(JsonValues)),
+            ),
+            dt if dt.is_numeric() => with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                serializer.collect_seq(ca)
+            }),
+            // temporal and other logical types are written in their string representation
+            _ => {
+                let s = s.cast(&DataType::Utf8).map_err(S::Error::custom)?;
+                serializer.collect_seq(s.utf8().unwrap())
+            }
+        }
+    }
+}
+
+impl Series {
+    /// Serialize the values to a JSON array, e.g. `[1,null,3]`.
+    ///
+    /// Numeric and boolean values are written as JSON numbers and booleans, lists as
+    /// nested arrays and the remaining types as strings. Set `pretty` to indent the output.
+    pub fn to_json(&self, pretty: bool) -> PolarsResult<String> {
+        let mut buf = Vec::with_capacity(self.len() * 4);
+        let values = JsonValues(self.clone());
+        if pretty {
+            serde_json::to_writer_pretty(&mut buf, &values)
+        } else {
+            serde_json::to_writer(&mut buf, &values)
+        }
+        .map_err(|e| PolarsError::ComputeError(format!("{e}").into()))?;
+        // Safety: serde_json only writes valid utf8
+        Ok(unsafe { String::from_utf8_unchecked(buf) })
+    }
+}
+
+/// The JSON value kinds that map to a column dtype.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum JsonKind {
+    Boolean,
+    Int,
+    Float,
+    Utf8,
+}
+
+impl JsonKind {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null => None,
+            Value::Bool(_) => Some(JsonKind::Boolean),
+            Value::Number(n) if n.is_i64() => Some(JsonKind::Int),
+            Value::Number(_) => Some(JsonKind::Float),
+            // nested values are re-serialized
+            Value::String(_) | Value::Array(_) | Value::Object(_) => Some(JsonKind::Utf8),
+        }
+    }
+
+    fn coerce(self, other: Self) -> Self {
+        use JsonKind::*;
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Int, Float) | (Float, Int) => Float,
+            _ => Utf8,
+        }
+    }
+}
+
+fn json_column(name: &str, values: &[Option<&Value>]) -> Series {
+    let kind = values
+        .iter()
+        .filter_map(|v| v.and_then(JsonKind::of))
+        .reduce(JsonKind::coerce);
+    let values = values.iter().map(|v| v.filter(|v| !v.is_null()));
+    let mut s = match kind {
+        None => Series::full_null(name, values.len(), &DataType::Null),
+        Some(JsonKind::Boolean) => values
+            .map(|v| v.and_then(Value::as_bool))
+            .collect::<BooleanChunked>()
+            .into_series(),
+        Some(JsonKind::Int) => values
+            .map(|v| v.and_then(Value::as_i64))
+            .collect::<Int64Chunked>()
+            .into_series(),
+        Some(JsonKind::Float) => values
+            .map(|v| v.and_then(Value::as_f64))
+            .collect::<Float64Chunked>()
+            .into_series(),
+        Some(JsonKind::Utf8) => values
+            .map(|v| {
+                v.map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    v => v.to_string(),
+                })
+            })
+            .collect::<Utf8Chunked>()
+            .into_series(),
+    };
+    s.rename(name);
+    s
+}
+
+impl DataFrame {
+    /// Parse a JSON array of records, e.g. `[{"a": 1}, {"a": 2, "b": "x"}]`.
+    ///
+    /// The columns are ordered by first appearance and missing keys are null. Columns with
+    /// both integers and floats become `Float64`, other mixed types and nested values
+    /// become `Utf8`, where non-string values are written as JSON.
+    pub fn from_json_str(json: &str) -> PolarsResult<DataFrame> {
+        let records = serde_json::from_str::<Vec<Value>>(json)
+            .map_err(|e| PolarsError::ComputeError(format!("{e}").into()))?;
+
+        let mut names = PlIndexSet::new();
+        for record in &records {
+            match record {
+                Value::Object(record) => names.extend(record.keys().map(|k| k.as_str())),
+                _ => {
+                    return Err(PolarsError::ComputeError(
+                        format!("expected a JSON object as record, got: {record}").into(),
+                    ))
+                }
+            }
+        }
+
+        let columns = names
+            .iter()
+            .map(|name| {
+                let values = records
+                    .iter()
+                    .map(|record| record.get(name))
+                    .collect::<Vec<_>>();
+                json_column(name, &values)
+            })
+            .collect::<Vec<_>>();
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_to_json() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(1), None, Some(3)]);
+        assert_eq!(s.to_json(false)?, "[1,null,3]");
+
+        let s = Series::new("a", [Some("a"), None]);
+        assert_eq!(s.to_json(false)?, r#"["a",null]"#);
+
+        let s = Series::new("a", [true, false]);
+        assert_eq!(s.to_json(true)?, "[\n  true,\n  false\n]");
+
+        let s = Series::new(
+            "a",
+            [Series::new("", [1.5, 2.0]), Series::new("", &[] as &[f64])],
+        );
+        assert_eq!(s.to_json(false)?, "[[1.5,2.0],[]]");
+        Ok(())
+    }
+
+    #[test]
+    fn test_df_from_json_str() -> PolarsResult<()> {
+        let json = r#"[
+            {"a": 1, "b": "x", "c": true},
+            {"a": 2.5, "b": 3, "d": {"e": 1}},
+            {"a": null, "b": null}
+        ]"#;
+        let df = DataFrame::from_json_str(json)?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c", "d"]);
+        assert_eq!(
+            df.dtypes(),
+            &[
+                DataType::Float64,
+                DataType::Utf8,
+                DataType::Boolean,
+                DataType::Utf8
+            ]
+        );
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            &[Some("x"), Some("3"), None]
+        );
+        assert_eq!(
+            Vec::from(df.column("d")?.utf8()?),
+            &[None, Some(r#"{"e":1}"#), None]
+        );
+        assert_eq!(
+            Vec::from(df.column("c")?.bool()?),
+            &[Some(true), None, None]
+        );
+
+        assert!(DataFrame::from_json_str("[1, 2]").is_err());
+        Ok(())
+    }
+}
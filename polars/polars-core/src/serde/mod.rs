@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use crate::prelude::*;
 
 pub mod chunked_array;
+#[cfg(feature = "to_json")]
+mod json;
 pub mod series;
 
 /// Intermediate enum. Needed because [crate::datatypes::DataType] has
@@ -221,6 +221,31 @@ impl Series {
         })
     }
 
+    /// Rechunk the [`Series`] into a single chunk, borrowing it if it already is.
+    pub fn rechunk_if_needed(&self) -> Cow<Series> {
+        if self.n_chunks() > 1 {
+            Cow::Owned(self.rechunk())
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+
+    /// Map every distinct value to the indices where it occurs, ordered by first appearance.
+    ///
+    /// If `include_nulls` is `true`, the null values form a group with key [`AnyValue::Null`],
+    /// otherwise they are skipped.
+    pub fn group_indices(
+        &self,
+        include_nulls: bool,
+    ) -> PolarsResult<Vec<(AnyValue, Vec<IdxSize>)>> {
+        let groups = self.group_tuples(false, true)?;
+        crate::frame::groupby::group_indices_ordered(&groups)
+            .into_iter()
+            .map(|(first, idx)| Ok((self.get(first as usize)?, idx)))
+            .filter(|out| include_nulls || !matches!(out, Ok((AnyValue::Null, _))))
+            .collect()
+    }
+
     /// Only implemented for numeric types
     pub fn as_single_ptr(&mut self) -> PolarsResult<usize> {
         self._get_inner_mut().as_single_ptr()
@@ -313,6 +338,43 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Returns the variance with `ddof` delta degrees of freedom, see
+    /// [`ChunkedArray::var_with_ddof`]. Returns `None` for types without a variance.
+    pub fn var(&self, ddof: u8) -> Option<f64> {
+        self.var_as_series(ddof)
+            .cast(&DataType::Float64)
+            .ok()
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
+    /// Returns the standard deviation with `ddof` delta degrees of freedom.
+    pub fn std(&self, ddof: u8) -> Option<f64> {
+        self.std_as_series(ddof)
+            .cast(&DataType::Float64)
+            .ok()
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
+    /// Compute the quantiles `qs` of a numeric Series, sorting the values only once.
+    /// See [`ChunkedArray::quantiles`].
+    pub fn quantiles(
+        &self,
+        qs: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Vec<Option<f64>>> {
+        match self.dtype() {
+            dt if dt.is_numeric() && !dt.is_temporal() => {
+                with_match_physical_numeric_polars_type!(dt, |$T| {
+                    let ca: &ChunkedArray<$T> = self.as_ref().as_ref().as_ref();
+                    ca.quantiles(qs, interpol)
+                })
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("quantiles not supported for dtype {dt:?}").into(),
+            )),
+        }
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> PolarsResult<Series> {
         match self.dtype() {
@@ -328,6 +390,24 @@ impl Series {
         }
     }
 
+    /// Decode a categorical Series to the string value of every category index.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn decode_categorical(&self) -> PolarsResult<Series> {
+        match self.dtype() {
+            DataType::Categorical(_) => Ok(Utf8Chunked::from_categorical_indices(
+                self.categorical().unwrap(),
+            )?
+            .into_series()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!(
+                    "'decode_categorical' not supported for series with dtype {:?}",
+                    self.dtype()
+                )
+                .into(),
+            )),
+        }
+    }
+
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
@@ -870,6 +950,67 @@ impl Series {
         };
         Ok(out)
     }
+    /// Repeat the values of this Series `n` times, producing a Series of length `self.len() * n`.
+    pub fn repeat(&self, n: usize) -> Series {
+        use DataType::*;
+        match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_) => {
+                let ca = self.categorical().unwrap();
+                let cats = ca.logical().repeat(n);
+                // Safety:
+                // the repeated indices are in bounds of the same rev map
+                return unsafe {
+                    CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        cats,
+                        ca.get_rev_map().clone(),
+                    )
+                }
+                .into_series();
+            }
+            #[cfg(feature = "dtype-struct")]
+            Struct(_) => {
+                let ca = self.struct_().unwrap();
+                let fields = ca.fields().iter().map(|s| s.repeat(n)).collect::<Vec<_>>();
+                return StructChunked::new(self.name(), &fields)
+                    .unwrap()
+                    .into_series();
+            }
+            _ => {}
+        }
+
+        let s = self.to_physical_repr();
+        let out = match s.dtype() {
+            dt if dt.is_numeric() => {
+                with_match_physical_numeric_polars_type!(dt, |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    ca.repeat(n).into_series()
+                })
+            }
+            Boolean => s.bool().unwrap().repeat(n).into_series(),
+            Utf8 => s.utf8().unwrap().repeat(n).into_series(),
+            #[cfg(feature = "dtype-binary")]
+            Binary => s.binary().unwrap().repeat(n).into_series(),
+            List(_) => s.list().unwrap().repeat(n).into_series(),
+            // the other dtypes, like nulls and objects, concatenate their chunks
+            _ => {
+                let mut out = self.slice(0, 0);
+                for _ in 0..n {
+                    // same dtype, so this cannot fail
+                    out.append(self).unwrap();
+                }
+                return match self.dtype() {
+                    #[cfg(feature = "object")]
+                    Object(_) => out,
+                    _ => out.rechunk(),
+                };
+            }
+        };
+        // Safety:
+        // `out` has the physical type of `self`
+        unsafe { out.cast_unchecked(self.dtype()).unwrap() }
+    }
+
     /// Get the head of the Series.
     pub fn head(&self, length: Option<usize>) -> Series {
         match length {
@@ -1028,6 +1169,18 @@ mod test {
     use crate::prelude::*;
     use crate::series::*;
 
+    #[test]
+    fn test_rechunk_if_needed() {
+        let mut s = Series::new("a", [1, 2]);
+        assert!(matches!(s.rechunk_if_needed(), Cow::Borrowed(_)));
+
+        s.append(&Series::new("a", [3])).unwrap();
+        let out = s.rechunk_if_needed();
+        assert!(matches!(out, Cow::Owned(_)));
+        assert_eq!(out.n_chunks(), 1);
+        assert!(out.series_equal(&s));
+    }
+
     #[test]
     fn cast() {
         let ar = UInt32Chunked::new("a", &[1, 2]);
@@ -1097,6 +1250,71 @@ mod test {
         let _ = series.slice(4, 2);
     }
 
+    #[test]
+    fn series_repeat() {
+        let series = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let out = series.repeat(3);
+        assert_eq!(out.len(), 9);
+        assert_eq!(out.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[
+                Some(1),
+                None,
+                Some(3),
+                Some(1),
+                None,
+                Some(3),
+                Some(1),
+                None,
+                Some(3)
+            ]
+        );
+        assert_eq!(series.repeat(1), series);
+        assert!(series.repeat(0).is_empty());
+
+        let series = Series::full_null("a", 2, &DataType::Null);
+        let out = series.repeat(3);
+        assert_eq!(out.dtype(), &DataType::Null);
+        assert_eq!(out.len(), 6);
+        assert_eq!(out.null_count(), 6);
+
+        let series = Series::new("a", &["x", "y"]);
+        let out = series.repeat(2);
+        assert_eq!(out.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[Some("x"), Some("y"), Some("x"), Some("y")]
+        );
+    }
+
+    #[test]
+    fn test_group_indices() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a", &[Some(3), None, Some(1), Some(3), None]);
+        assert_eq!(
+            ca.group_indices(true)?,
+            &[
+                (Some(3), vec![0, 3]),
+                (None, vec![1, 4]),
+                (Some(1), vec![2])
+            ]
+        );
+        assert_eq!(
+            ca.group_indices(false)?,
+            &[(Some(3), vec![0, 3]), (Some(1), vec![2])]
+        );
+
+        let s = Series::new("a", &[Some("b"), None, Some("a"), Some("b")]);
+        assert_eq!(
+            s.group_indices(false)?,
+            &[
+                (AnyValue::Utf8("b"), vec![0, 3]),
+                (AnyValue::Utf8("a"), vec![2])
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "round_series")]
     fn test_round_series() {
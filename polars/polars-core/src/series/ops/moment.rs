@@ -1,40 +1,100 @@
+use num::ToPrimitive;
+
 use crate::prelude::*;
 
-fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult<Option<f64>> {
-    // see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L922
-    let out = match moment {
-        0 => Some(1.0),
-        1 => Some(0.0),
-        _ => {
-            let mut n_list = vec![moment];
-            let mut current_n = moment;
-            while current_n > 2 {
-                if current_n % 2 == 1 {
-                    current_n = (current_n - 1) / 2
-                } else {
-                    current_n /= 2
-                }
-                n_list.push(current_n)
-            }
+/// Central moment sums of a data set, updated in a single pass.
+///
+/// see: https://people.xiph.org/~tterribe/notes/homs.html
+#[derive(Default)]
+struct CentralMoments {
+    n: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
 
-            let a_zero_mean = s.cast(&DataType::Float64)? - mean;
+impl CentralMoments {
+    #[inline]
+    fn push(&mut self, x: f64) {
+        let n1 = self.n;
+        self.n += 1.0;
+        let n = self.n;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
 
-            let mut s = if n_list.pop().unwrap() == 1 {
-                a_zero_mean.clone()
-            } else {
-                &a_zero_mean * &a_zero_mean
-            };
+    fn skew(&self, bias: bool) -> f64 {
+        let n = self.n;
+        let out = n.sqrt() * self.m3 / self.m2.powf(1.5);
+        if bias {
+            out
+        } else {
+            ((n - 1.0) * n).sqrt() / (n - 2.0) * out
+        }
+    }
 
-            for n in n_list.iter().rev() {
-                s = &s * &s;
-                if n % 2 == 1 {
-                    s = &s * &a_zero_mean;
-                }
+    fn kurtosis(&self, fisher: bool, bias: bool) -> f64 {
+        let n = self.n;
+        let out = n * self.m4 / self.m2.powf(2.0);
+        let out = if bias {
+            out
+        } else {
+            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
+                * ((n.powf(2.0) - 1.0) * out - 3.0 * (n - 1.0).powf(2.0))
+        };
+        if fisher {
+            out - 3.0
+        } else {
+            out
+        }
+    }
+}
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    fn central_moments(&self) -> CentralMoments {
+        let mut moments = CentralMoments::default();
+        for arr in self.downcast_iter() {
+            for v in arr.iter().flatten() {
+                moments.push(v.to_f64().unwrap())
             }
-            s.mean()
         }
-    };
-    Ok(out)
+        moments
+    }
+
+    /// Compute the (biased) sample skewness in a single pass.
+    ///
+    /// Returns `None` if there are fewer than 3 non-null values.
+    pub fn skewness(&self) -> Option<f64> {
+        if self.len() - self.null_count() < 3 {
+            return None;
+        }
+        Some(self.central_moments().skew(true))
+    }
+
+    /// Compute the (biased) kurtosis in a single pass. If `fisher` is `true`, 3.0 is
+    /// subtracted from the result to give 0.0 for a normal distribution.
+    ///
+    /// Returns `None` if there are fewer than 4 non-null values.
+    pub fn kurtosis(&self, fisher: bool) -> Option<f64> {
+        if self.len() - self.null_count() < 4 {
+            return None;
+        }
+        Some(self.central_moments().kurtosis(fisher, true))
+    }
+}
+
+fn central_moments(s: &Series) -> PolarsResult<Option<CentralMoments>> {
+    let s = s.cast(&DataType::Float64)?;
+    let moments = s.f64().unwrap().central_moments();
+    Ok((moments.n > 0.0).then_some(moments))
 }
 
 impl Series {
@@ -48,22 +108,7 @@ impl Series {
     ///
     /// see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1024
     pub fn skew(&self, bias: bool) -> PolarsResult<Option<f64>> {
-        let mean = match self.mean() {
-            Some(mean) => mean,
-            None => return Ok(None),
-        };
-        // we can unwrap because if it were None, we already return None above
-        let m2 = moment_precomputed_mean(self, 2, mean)?.unwrap();
-        let m3 = moment_precomputed_mean(self, 3, mean)?.unwrap();
-
-        let out = m3 / m2.powf(1.5);
-
-        if !bias {
-            let n = (self.len() - self.null_count()) as f64;
-            Ok(Some(((n - 1.0) * n).sqrt() / (n - 2.0) * out))
-        } else {
-            Ok(Some(out))
-        }
+        Ok(central_moments(self)?.map(|m| m.skew(bias)))
     }
 
     /// Compute the kurtosis (Fisher or Pearson) of a dataset.
@@ -76,26 +121,7 @@ impl Series {
     ///
     /// see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1027
     pub fn kurtosis(&self, fisher: bool, bias: bool) -> PolarsResult<Option<f64>> {
-        let mean = match self.mean() {
-            Some(mean) => mean,
-            None => return Ok(None),
-        };
-        // we can unwrap because if it were None, we already return None above
-        let m2 = moment_precomputed_mean(self, 2, mean)?.unwrap();
-        let m4 = moment_precomputed_mean(self, 4, mean)?.unwrap();
-
-        let out = if !bias {
-            let n = (self.len() - self.null_count()) as f64;
-            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
-                * ((n.powf(2.0) - 1.0) * m4 / m2.powf(2.0) - 3.0 * (n - 1.0).powf(2.0))
-        } else {
-            m4 / m2.powf(2.0)
-        };
-        if fisher {
-            Ok(Some(out - 3.0))
-        } else {
-            Ok(Some(out))
-        }
+        Ok(central_moments(self)?.map(|m| m.kurtosis(fisher, bias)))
     }
 }
 
@@ -103,6 +129,43 @@ impl Series {
 mod test {
     use super::*;
 
+    fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult<Option<f64>> {
+        // see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L922
+        let out = match moment {
+            0 => Some(1.0),
+            1 => Some(0.0),
+            _ => {
+                let mut n_list = vec![moment];
+                let mut current_n = moment;
+                while current_n > 2 {
+                    if current_n % 2 == 1 {
+                        current_n = (current_n - 1) / 2
+                    } else {
+                        current_n /= 2
+                    }
+                    n_list.push(current_n)
+                }
+
+                let a_zero_mean = s.cast(&DataType::Float64)? - mean;
+
+                let mut s = if n_list.pop().unwrap() == 1 {
+                    a_zero_mean.clone()
+                } else {
+                    &a_zero_mean * &a_zero_mean
+                };
+
+                for n in n_list.iter().rev() {
+                    s = &s * &s;
+                    if n % 2 == 1 {
+                        s = &s * &a_zero_mean;
+                    }
+                }
+                s.mean()
+            }
+        };
+        Ok(out)
+    }
+
     impl Series {
         fn moment(&self, moment: usize) -> PolarsResult<Option<f64>> {
             match self.mean() {
@@ -158,4 +221,18 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_chunked_skewness_kurtosis() {
+        let ca = Int32Chunked::new("", &[1, 2, 3, 4, 5, 23]);
+        assert!((ca.skewness().unwrap() - 1.6727687946848508).abs() < 0.0001);
+        assert!((ca.kurtosis(true).unwrap() - 0.9945668771797536).abs() < 0.0001);
+        assert!((ca.kurtosis(false).unwrap() - 3.994566877179754).abs() < 0.0001);
+
+        let ca = Float64Chunked::new("", &[Some(1.0), None, Some(2.0), Some(3.0)]);
+        assert!(ca.skewness().is_some());
+        assert_eq!(ca.kurtosis(true), None);
+        let ca = Float64Chunked::new("", &[Some(1.0), None, Some(2.0)]);
+        assert_eq!(ca.skewness(), None);
+    }
 }
@@ -60,9 +60,26 @@ impl Series {
         Ok(ca)
     }
 
+    /// Reshape the values of this Series. A `-1` in `dims` infers that dimension from the
+    /// length. One dimension returns the values, two dimensions return a list Series with
+    /// `dims[0]` lists of `dims[1]` values, e.g. `[3, -1]` turns 12 values into 3 lists of 4.
+    ///
+    /// A list Series is exploded first. Returns an error if the dimensions do not match the
+    /// length, if more than one dimension is inferred or if more than two dimensions are given.
     pub fn reshape(&self, dims: &[i64]) -> PolarsResult<Series> {
-        if dims.is_empty() {
-            panic!("dimensions cannot be empty")
+        if dims.is_empty() || dims.len() > 2 {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot reshape into {} dimensions, only 1 or 2 are supported",
+                    dims.len()
+                )
+                .into(),
+            ));
+        }
+        if dims.iter().any(|dim| *dim < -1) || dims.iter().filter(|dim| **dim == -1).count() > 1 {
+            return Err(PolarsError::ComputeError(
+                format!("invalid shape {dims:?}, only a single dimension can be -1").into(),
+            ));
         }
         let s = if let DataType::List(_) = self.dtype() {
             Cow::Owned(self.explode()?)
@@ -87,6 +104,11 @@ impl Series {
                     product *= *dim
                 }
             }
+            if product == 0 {
+                return Err(PolarsError::ComputeError(
+                    format!("cannot infer a dimension of shape {dims:?}").into(),
+                ));
+            }
             dims[idx] = s_ref.len() as i64 / product;
         }
 
@@ -100,16 +122,8 @@ impl Series {
         match dims.len() {
             1 => Ok(s_ref.slice(0, dims[0] as usize)),
             2 => {
-                let mut rows = dims[0];
-                let mut cols = dims[1];
-
-                // infer dimension
-                if rows == -1 {
-                    rows = cols / s_ref.len() as i64
-                }
-                if cols == -1 {
-                    cols = rows / s_ref.len() as i64
-                }
+                let rows = dims[0];
+                let cols = dims[1];
 
                 // fast path, we can create a unit list so we only allocate offsets
                 if rows as usize == s_ref.len() && cols == 1 {
@@ -128,9 +142,7 @@ impl Series {
                 }
                 Ok(builder.finish().into_series())
             }
-            _ => {
-                panic!("more than two dimensions not yet supported");
-            }
+            _ => unreachable!(),
         }
     }
 }
@@ -171,6 +183,24 @@ mod test {
             assert_eq!(out.explode()?.len(), 4);
         }
 
+        let s = Series::new("a", (0..12).collect::<Vec<i32>>());
+        let out = s.reshape(&[3, -1])?;
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.list()?.get(2).unwrap().len(), 4);
+        assert_eq!(s.reshape(&[-1])?.len(), 12);
+
+        for dims in [
+            &[5, -1][..],
+            &[3, 3],
+            &[-1, -1],
+            &[-2, 6],
+            &[-1, 0],
+            &[],
+            &[2, 2, 3],
+        ] {
+            assert!(s.reshape(dims).is_err());
+        }
+
         Ok(())
     }
 }
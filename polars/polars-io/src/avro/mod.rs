@@ -12,6 +12,49 @@ fn convert_err(e: AvroError) -> PolarsError {
     PolarsError::ComputeError(format!("{e}").into())
 }
 
+/// Read and write a [`DataFrame`] in Apache Avro format.
+///
+/// Avro `int`, `long`, `float`, `double` and `string` map to `Int32`, `Int64`,
+/// `Float32`, `Float64` and `Utf8`. A `["null", T]` union maps to a nullable column
+/// of the type that `T` maps to.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use polars_core::prelude::*;
+/// use polars_io::avro::AvroDataFrameExt;
+///
+/// fn example(df: &DataFrame) -> PolarsResult<DataFrame> {
+///     let mut buf = vec![];
+///     df.to_avro(&mut buf, None)?;
+///     DataFrame::from_avro(Cursor::new(buf))
+/// }
+/// ```
+pub trait AvroDataFrameExt: Sized {
+    /// Read an Avro file. The reader does not have to be seekable.
+    fn from_avro<R: Read>(reader: R) -> PolarsResult<Self>;
+
+    /// Write an Avro file. If `schema` is `None`, the schema is derived from the
+    /// columns; see [`AvroWriter::with_schema`] for the requirements on a given schema.
+    fn to_avro<W: Write>(&self, writer: W, schema: Option<AvroSchema>) -> PolarsResult<()>;
+}
+
+impl AvroDataFrameExt for DataFrame {
+    fn from_avro<R: Read>(reader: R) -> PolarsResult<Self> {
+        AvroReader::from_stream(reader).finish_impl()
+    }
+
+    fn to_avro<W: Write>(&self, writer: W, schema: Option<AvroSchema>) -> PolarsResult<()> {
+        // the column chunks must be aligned to iterate over the record batches
+        let mut df = self.clone();
+        if df.should_rechunk() {
+            df.rechunk();
+        }
+        AvroWriter::new(writer).with_schema(schema).finish(&mut df)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -19,7 +62,7 @@ mod test {
     use polars_core::df;
     use polars_core::prelude::*;
 
-    use super::{write, AvroReader, AvroWriter};
+    use super::{write, AvroDataFrameExt, AvroReader, AvroWriter};
     use crate::prelude::*;
 
     #[test]
@@ -104,4 +147,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_to_and_from_avro() -> PolarsResult<()> {
+        let mut df = df!(
+            "i32" => &[Some(1i32), None, Some(3)],
+            "i64" => &[1i64, 2, 3],
+            "f32" => &[Some(0.1f32), Some(0.2), None],
+            "f64" => &[0.1, 0.2, 0.3],
+            "utf8" => &[None, Some("b"), Some("c")]
+        )?;
+        // multiple chunks are written as multiple blocks
+        df.vstack_mut(&df.clone())?;
+
+        let mut buf = vec![];
+        df.to_avro(&mut buf, None)?;
+        let read_df = DataFrame::from_avro(buf.as_slice())?;
+        assert!(df.frame_equal_missing(&read_df));
+        assert_eq!(read_df.dtypes(), df.dtypes());
+
+        // a required field is accepted for a column without nulls
+        let df = df.select(["i64", "utf8"])?;
+        let mut schema = arrow::io::avro::write::to_record(&df.schema().to_arrow()).unwrap();
+        schema.fields[0].schema = write::AvroType::Long(None);
+        let mut buf = vec![];
+        df.to_avro(&mut buf, Some(schema.clone()))?;
+        let read_df = DataFrame::from_avro(buf.as_slice())?;
+        assert!(df.frame_equal_missing(&read_df));
+
+        // but not for a column with nulls
+        let mut nullable = schema.clone();
+        nullable.fields[1].schema = write::AvroType::String(None);
+        assert!(df.to_avro(vec![], Some(nullable)).is_err());
+
+        let mut wrong_type = schema.clone();
+        wrong_type.fields[0].schema = write::AvroType::Double;
+        assert!(df.to_avro(vec![], Some(wrong_type)).is_err());
+
+        let mut wrong_name = schema;
+        wrong_name.fields[0].name = "a".into();
+        assert!(df.to_avro(vec![], Some(wrong_name)).is_err());
+        Ok(())
+    }
 }
@@ -69,7 +69,7 @@ impl<R: Read + Seek> AvroReader<R> {
 
 impl<R> ArrowReader for read::Reader<R>
 where
-    R: Read + Seek,
+    R: Read,
 {
     fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
         self.next().map_or(Ok(None), |v| v.map(Some))
@@ -81,6 +81,22 @@ where
     R: Read + Seek,
 {
     fn new(reader: R) -> Self {
+        Self::from_stream(reader)
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> PolarsResult<DataFrame> {
+        self.finish_impl()
+    }
+}
+
+impl<R: Read> AvroReader<R> {
+    /// Create a reader that does not require seeking, e.g. for streams.
+    pub(super) fn from_stream(reader: R) -> Self {
         AvroReader {
             reader,
             rechunk: true,
@@ -90,12 +106,9 @@ where
         }
     }
 
-    fn set_rechunk(mut self, rechunk: bool) -> Self {
-        self.rechunk = rechunk;
-        self
-    }
-
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    // Reading the metadata and the blocks only advances the reader, so unlike
+    // `SerReader` this does not require `Seek`.
+    pub(super) fn finish_impl(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(convert_err)?;
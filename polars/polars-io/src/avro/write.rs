@@ -1,4 +1,7 @@
 pub use arrow::io::avro::avro_schema::file::Compression;
+/// The Avro record schema of a file; each field is a column.
+pub use arrow::io::avro::avro_schema::schema::Record as AvroSchema;
+pub(super) use arrow::io::avro::avro_schema::schema::Schema as AvroType;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 pub use Compression as AvroCompression;
@@ -26,6 +29,7 @@ use super::*;
 pub struct AvroWriter<W> {
     writer: W,
     compression: Option<AvroCompression>,
+    schema: Option<AvroSchema>,
 }
 
 impl<W> AvroWriter<W>
@@ -37,6 +41,79 @@ where
         self.compression = compression;
         self
     }
+
+    /// Set the Avro schema that is written. Defaults to None, in which case the
+    /// schema is derived from the [`DataFrame`].
+    ///
+    /// The fields must match the columns by name and order, and their types must match
+    /// the types of the columns. Nullable columns are written as a `["null", T]` union;
+    /// a field that is not a union cannot be used for a column that contains nulls.
+    pub fn with_schema(mut self, schema: Option<AvroSchema>) -> Self {
+        self.schema = schema;
+        self
+    }
+}
+
+/// Strip the null variant of a `["null", T]` union. Other unions are not supported
+/// by the serializers, so they are compared as is.
+fn non_null_type(schema: &AvroType) -> (&AvroType, bool) {
+    match schema {
+        AvroType::Union(types) if types.len() == 2 && types[0] == AvroType::Null => {
+            (&types[1], true)
+        }
+        _ => (schema, false),
+    }
+}
+
+fn check_schema(schema: &AvroSchema, inferred: &AvroSchema, df: &DataFrame) -> PolarsResult<()> {
+    if schema.fields.len() != inferred.fields.len() {
+        return Err(PolarsError::SchemaMisMatch(
+            format!(
+                "avro schema has {} fields, but the DataFrame has {} columns",
+                schema.fields.len(),
+                inferred.fields.len()
+            )
+            .into(),
+        ));
+    }
+    for ((field, expected), s) in schema
+        .fields
+        .iter()
+        .zip(inferred.fields.iter())
+        .zip(df.get_columns())
+    {
+        if field.name != expected.name {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "avro field '{}' does not match column '{}'",
+                    field.name, expected.name
+                )
+                .into(),
+            ));
+        }
+        let (dtype, nullable) = non_null_type(&field.schema);
+        if dtype != non_null_type(&expected.schema).0 {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "avro field '{}' has type {:?}, which cannot hold column of dtype {}",
+                    field.name,
+                    field.schema,
+                    s.dtype()
+                )
+                .into(),
+            ));
+        }
+        if !nullable && s.null_count() > 0 {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "avro field '{}' is not nullable, but column contains nulls",
+                    field.name
+                )
+                .into(),
+            ));
+        }
+    }
+    Ok(())
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -47,12 +124,23 @@ where
         Self {
             writer,
             compression: None,
+            schema: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         let schema = df.schema().to_arrow();
-        let record = write::to_record(&schema)?;
+        let inferred = write::to_record(&schema)?;
+        let record = match &self.schema {
+            Some(record) => {
+                check_schema(record, &inferred, df)?;
+                record.clone()
+            }
+            None => inferred,
+        };
+
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            .map_err(convert_err)?;
 
         let mut data = vec![];
         let mut compressed_block = avro_schema::file::CompressedBlock::default();
@@ -70,9 +158,6 @@ where
                 avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
                     .map_err(convert_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(convert_err)?;
-
             avro_schema::write::write_block(&mut self.writer, &compressed_block)
                 .map_err(convert_err)?;
             // reuse block for next iteration.
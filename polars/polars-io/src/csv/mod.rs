@@ -54,7 +54,7 @@ pub(super) mod write_impl;
 
 use std::borrow::Cow;
 use std::fs::File;
-use std::io::Write;
+use std::io::{Cursor, Write};
 use std::path::PathBuf;
 
 use polars_core::prelude::*;
@@ -65,7 +65,7 @@ use rayon::prelude::*;
 pub use read::{CsvEncoding, CsvReader, NullValues};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-pub use write::CsvWriter;
+pub use write::{CsvDataFrameExt, CsvWriteOptions, CsvWriter};
 
 use crate::csv::read_impl::CoreReader;
 use crate::csv::utils::get_reader_bytes;
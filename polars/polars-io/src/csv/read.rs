@@ -117,6 +117,7 @@ where
     path: Option<PathBuf>,
     schema_overwrite: Option<&'a Schema>,
     dtype_overwrite: Option<&'a [DataType]>,
+    schema_override: Option<PlHashMap<String, DataType>>,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
@@ -245,6 +246,15 @@ where
         self
     }
 
+    /// Force the columns in `overrides` to be read as the given dtype, regardless of what
+    /// schema inference decides. This is applied on top of [`CsvReader::with_dtypes`].
+    ///
+    /// Columns that are not found in the csv are ignored, and logged if `POLARS_VERBOSE` is set.
+    pub fn with_schema_override(mut self, overrides: PlHashMap<String, DataType>) -> Self {
+        self.schema_override = Some(overrides);
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     ///
     /// # Arguments
@@ -328,6 +338,15 @@ impl<'a> CsvReader<'a, File> {
     }
 }
 
+impl<'a> CsvReader<'a, Cursor<&'a [u8]>> {
+    /// Read CSV data from an in-memory byte slice.
+    ///
+    /// The slice is parsed in place, so the data is not copied into an intermediate buffer.
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self::new(Cursor::new(bytes))
+    }
+}
+
 impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     fn core_reader<'b>(
         &'b mut self,
@@ -369,6 +388,23 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
         )
     }
 
+    /// Merge the overrides of [`CsvReader::with_schema_override`] into the overwriting schema,
+    /// so that they are respected by the parser. The names of the overrides are returned
+    /// as well.
+    fn take_schema_override(&mut self) -> (Option<Schema>, Vec<String>) {
+        match self.schema_override.take() {
+            Some(overrides) => {
+                let names = overrides.keys().cloned().collect();
+                let mut schema = self.schema_overwrite.cloned().unwrap_or_default();
+                for (name, dtype) in overrides {
+                    schema.with_column(name, dtype);
+                }
+                (Some(schema), names)
+            }
+            None => (None, vec![]),
+        }
+    }
+
     fn prepare_schema_overwrite(&self, overwriting_schema: &Schema) -> (Schema, Vec<Field>, bool) {
         // This branch we check if there are dtypes we cannot parse.
         // We only support a few dtypes in the parser and later cast to the required dtype
@@ -404,7 +440,8 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     }
 
     pub fn batched_borrowed(&'a mut self) -> PolarsResult<BatchedCsvReader<'a>> {
-        if let Some(schema) = self.schema_overwrite {
+        let (schema_override, override_names) = self.take_schema_override();
+        if let Some(schema) = schema_override.as_ref().or(self.schema_overwrite) {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema);
             self.owned_schema = Some(Box::new(schema));
 
@@ -419,6 +456,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             };
 
             let csv_reader = self.core_reader(schema, to_cast)?;
+            log_missing_override_columns(&override_names, csv_reader.schema());
             csv_reader.batched(has_cat)
         } else {
             let csv_reader = self.core_reader(self.schema, vec![])?;
@@ -478,6 +516,7 @@ where
             path: None,
             schema_overwrite: None,
             dtype_overwrite: None,
+            schema_override: None,
             sample_size: 1024,
             chunk_size: 1 << 18,
             low_memory: false,
@@ -497,7 +536,8 @@ where
     /// Read the file and create the DataFrame.
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
-        let schema_overwrite = self.schema_overwrite;
+        let (schema_override, override_names) = self.take_schema_override();
+        let schema_overwrite = schema_override.as_ref().or(self.schema_overwrite);
         let dtype_overwrite = self.dtype_overwrite;
         let should_parse_dates = self.parse_dates;
         let low_memory = self.low_memory;
@@ -514,6 +554,7 @@ where
             }
 
             let mut csv_reader = self.core_reader(Some(&schema), to_cast)?;
+            log_missing_override_columns(&override_names, csv_reader.schema());
             csv_reader.as_df()?
         } else {
             #[cfg(feature = "dtype-categorical")]
@@ -566,6 +607,14 @@ where
     }
 }
 
+fn log_missing_override_columns(names: &[String], schema: &Schema) {
+    if std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("0") == "1" {
+        for name in names.iter().filter(|name| !schema.contains(name)) {
+            eprintln!("column '{name}' of the schema override was not found in the csv")
+        }
+    }
+}
+
 #[cfg(feature = "temporal")]
 fn parse_dates(mut df: DataFrame, fixed_schema: &Schema) -> DataFrame {
     let cols = std::mem::take(df.get_columns_mut())
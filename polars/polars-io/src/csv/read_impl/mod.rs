@@ -288,6 +288,10 @@ impl<'a> CoreReader<'a> {
         })
     }
 
+    pub(crate) fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
     fn find_starting_point<'b>(
         &self,
         mut bytes: &'b [u8],
@@ -1,5 +1,27 @@
 use super::*;
 
+/// Options to configure a [`CsvWriter`] in one go, see [`CsvWriter::with_options`].
+#[derive(Clone, Debug)]
+pub struct CsvWriteOptions {
+    pub delimiter: u8,
+    pub quote: u8,
+    pub has_header: bool,
+    pub null_value: String,
+    pub date_format: Option<String>,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        CsvWriteOptions {
+            delimiter: b',',
+            quote: b'"',
+            has_header: true,
+            null_value: String::new(),
+            date_format: None,
+        }
+    }
+}
+
 /// Write a DataFrame to csv.
 ///
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
@@ -105,4 +127,29 @@ where
         self.options.null = null_value;
         self
     }
+
+    /// Set the delimiter, quoting character, header, null value representation and date
+    /// format from `options`.
+    pub fn with_options(self, options: CsvWriteOptions) -> Self {
+        self.with_delimiter(options.delimiter)
+            .with_quoting_char(options.quote)
+            .has_header(options.has_header)
+            .with_null_value(options.null_value)
+            .with_date_format(options.date_format)
+    }
+}
+
+/// Write a [`DataFrame`] as csv to any [`Write`] implementor.
+pub trait CsvDataFrameExt {
+    /// Write the [`DataFrame`] as csv configured by `options`, e.g. to a socket or an
+    /// in-memory buffer. The rows are serialized and written in batches, so only a single
+    /// batch is buffered at a time.
+    fn write_csv<W: Write>(&self, writer: W, options: CsvWriteOptions) -> PolarsResult<()>;
+}
+
+impl CsvDataFrameExt for DataFrame {
+    fn write_csv<W: Write>(&self, writer: W, options: CsvWriteOptions) -> PolarsResult<()> {
+        let mut df = self.clone();
+        CsvWriter::new(writer).with_options(options).finish(&mut df)
+    }
 }
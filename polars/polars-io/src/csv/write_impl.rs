@@ -12,30 +12,37 @@ use polars_utils::contention_pool::LowContentionPool;
 use rayon::prelude::*;
 
 fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> std::io::Result<()> {
+    let quote = options.quote;
     if v.is_empty() {
-        write!(f, "\"\"")
-    } else {
-        let needs_escaping = memchr(options.quote, v.as_bytes()).is_some();
+        f.extend_from_slice(&[quote, quote]);
+        return Ok(());
+    }
+    let needs_escaping = memchr(quote, v.as_bytes()).is_some();
 
-        if needs_escaping {
-            let replaced = unsafe {
-                // replace from single quote "
-                // to double quote ""
-                v.replace(
-                    std::str::from_utf8_unchecked(&[options.quote]),
-                    std::str::from_utf8_unchecked(&[options.quote, options.quote]),
-                )
-            };
-            return write!(f, "\"{replaced}\"");
-        }
-        let surround_with_quotes = memchr2(options.delimiter, b'\n', v.as_bytes()).is_some();
+    if needs_escaping {
+        let replaced = unsafe {
+            // replace from single quote "
+            // to double quote ""
+            v.replace(
+                std::str::from_utf8_unchecked(&[quote]),
+                std::str::from_utf8_unchecked(&[quote, quote]),
+            )
+        };
+        f.push(quote);
+        f.extend_from_slice(replaced.as_bytes());
+        f.push(quote);
+        return Ok(());
+    }
+    let surround_with_quotes = memchr2(options.delimiter, b'\n', v.as_bytes()).is_some();
 
-        if surround_with_quotes {
-            write!(f, "\"{v}\"")
-        } else {
-            write!(f, "{v}")
-        }
+    if surround_with_quotes {
+        f.push(quote);
+        f.extend_from_slice(v.as_bytes());
+        f.push(quote);
+    } else {
+        f.extend_from_slice(v.as_bytes());
     }
+    Ok(())
 }
 
 fn fast_float_write<N: ToLexical>(f: &mut Vec<u8>, n: N, write_size: usize) -> std::io::Result<()> {
@@ -0,0 +1,376 @@
+//! Write a [`DataFrame`] to a [Delta Lake](https://delta.io) table.
+//!
+//! A Delta table is a directory of Parquet files together with a transaction log in
+//! `_delta_log/`. Every write adds one Parquet file and one numbered JSON commit to the log
+//! that adds the new file and, when overwriting, removes the files that were active before.
+//! Tables that contain checkpoints are not supported, as we only replay the JSON commits.
+use std::collections::hash_map::RandomState;
+use std::fs::{File, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_core::prelude::*;
+use serde_json::{json, Value};
+
+use crate::parquet::{ParquetCompression, ParquetWriter};
+use crate::utils::resolve_homedir;
+
+const LOG_DIR: &str = "_delta_log";
+
+/// What to do when writing to a path that already contains a Delta table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeltaWriteMode {
+    /// Raise an error if the table already exists.
+    ErrorIfExists,
+    /// Add the rows to the table. The schema must match that of the table.
+    Append,
+    /// Replace the contents (and schema) of the table.
+    Overwrite,
+}
+
+impl DeltaWriteMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DeltaWriteMode::ErrorIfExists => "ErrorIfExists",
+            DeltaWriteMode::Append => "Append",
+            DeltaWriteMode::Overwrite => "Overwrite",
+        }
+    }
+}
+
+/// Write a [`DataFrame`] to a Delta Lake table.
+pub trait DeltaDataFrameExt {
+    /// Write the [`DataFrame`] as a new Parquet file of the Delta table at `path` and commit
+    /// it to the table's transaction log. The table is created if it doesn't exist.
+    ///
+    /// Unsigned integers have no Delta equivalent and must be cast before writing.
+    /// Datetimes are written with microsecond precision.
+    fn write_delta(&self, path: &str, mode: DeltaWriteMode) -> PolarsResult<()>;
+}
+
+impl DeltaDataFrameExt for DataFrame {
+    fn write_delta(&self, path: &str, mode: DeltaWriteMode) -> PolarsResult<()> {
+        let root = resolve_homedir(Path::new(path));
+        let log_dir = root.join(LOG_DIR);
+        let versions = log_versions(&log_dir)?;
+        let schema = delta_schema(&self.schema())?;
+
+        // files that are active in the latest version of the table
+        let mut active_files = vec![];
+        if !versions.is_empty() {
+            if mode == DeltaWriteMode::ErrorIfExists {
+                return Err(PolarsError::ComputeError(
+                    format!("delta table already exists at {path:?}").into(),
+                ));
+            }
+            let (table_schema, files) = replay_log(&log_dir, &versions)?;
+            if mode == DeltaWriteMode::Append && !same_fields(table_schema.as_ref(), &schema) {
+                return Err(PolarsError::SchemaMisMatch(
+                    format!("cannot append to delta table at {path:?}: schema does not match")
+                        .into(),
+                ));
+            }
+            active_files = files;
+        }
+        let version = versions.last().map_or(0, |v| v + 1);
+        std::fs::create_dir_all(&log_dir)?;
+
+        let now = now_millis();
+        let file_name = format!("part-{:05}-{}.snappy.parquet", version, random_uuid());
+        let mut df = to_delta_columns(self)?;
+        let size = ParquetWriter::new(File::create(root.join(&file_name))?)
+            .with_compression(ParquetCompression::Snappy)
+            .finish(&mut df)?;
+
+        let mut actions = vec![json!({
+            "commitInfo": {
+                "timestamp": now,
+                "operation": "WRITE",
+                "operationParameters": { "mode": mode.as_str() },
+            }
+        })];
+        if version == 0 {
+            actions.push(json!({
+                "protocol": { "minReaderVersion": 1, "minWriterVersion": 2 }
+            }));
+        }
+        if version == 0 || mode == DeltaWriteMode::Overwrite {
+            actions.push(json!({
+                "metaData": {
+                    "id": random_uuid(),
+                    "format": { "provider": "parquet", "options": {} },
+                    "schemaString": schema.to_string(),
+                    "partitionColumns": [],
+                    "configuration": {},
+                    "createdTime": now,
+                }
+            }));
+        }
+        if mode == DeltaWriteMode::Overwrite {
+            actions.extend(active_files.into_iter().map(|path| {
+                json!({
+                    "remove": { "path": path, "deletionTimestamp": now, "dataChange": true }
+                })
+            }));
+        }
+        actions.push(json!({
+            "add": {
+                "path": file_name,
+                "partitionValues": {},
+                "size": size,
+                "modificationTime": now,
+                "dataChange": true,
+            }
+        }));
+
+        // `create_new` makes sure we don't overwrite a commit of a concurrent writer
+        let mut commit = match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(log_dir.join(format!("{version:020}.json")))
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "version {version} of delta table at {path:?} was written concurrently"
+                    )
+                    .into(),
+                ))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        for action in actions {
+            writeln!(commit, "{action}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The sorted versions of the commits in the transaction log.
+fn log_versions(log_dir: &Path) -> PolarsResult<Vec<u64>> {
+    if !log_dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut versions = vec![];
+    for entry in std::fs::read_dir(log_dir)? {
+        let name = entry?.file_name();
+        let name = name.to_string_lossy();
+        if name.ends_with(".checkpoint.parquet") || name == "_last_checkpoint" {
+            return Err(PolarsError::ComputeError(
+                "delta tables with checkpoints are not supported".into(),
+            ));
+        }
+        if let Some(version) = name.strip_suffix(".json") {
+            if let Ok(version) = version.parse::<u64>() {
+                versions.push(version)
+            }
+        }
+    }
+    versions.sort_unstable();
+    Ok(versions)
+}
+
+/// Replay the commits of the transaction log and return the latest schema and the
+/// data files that are active in the latest version.
+fn replay_log(log_dir: &Path, versions: &[u64]) -> PolarsResult<(Option<Value>, Vec<String>)> {
+    let invalid = |version: u64| {
+        PolarsError::ComputeError(format!("invalid delta log commit {version}").into())
+    };
+
+    let mut schema = None;
+    let mut files: Vec<String> = vec![];
+    for &version in versions {
+        let commit = std::fs::read_to_string(log_dir.join(format!("{version:020}.json")))?;
+        for line in commit.lines().filter(|line| !line.trim().is_empty()) {
+            let action: Value = serde_json::from_str(line).map_err(|_| invalid(version))?;
+            if let Some(path) = action.pointer("/add/path").and_then(Value::as_str) {
+                files.push(path.to_string());
+            } else if let Some(path) = action.pointer("/remove/path").and_then(Value::as_str) {
+                files.retain(|f| f != path);
+            } else if let Some(schema_string) = action
+                .pointer("/metaData/schemaString")
+                .and_then(Value::as_str)
+            {
+                schema = Some(serde_json::from_str(schema_string).map_err(|_| invalid(version))?);
+            }
+        }
+    }
+    Ok((schema, files))
+}
+
+/// Check that both schemas have the same field names and types, in the same order.
+fn same_fields(table_schema: Option<&Value>, schema: &Value) -> bool {
+    let fields = |schema: &Value| {
+        schema["fields"].as_array().map(|fields| {
+            fields
+                .iter()
+                .map(|field| (field["name"].clone(), field["type"].clone()))
+                .collect::<Vec<_>>()
+        })
+    };
+    match table_schema {
+        Some(table_schema) => fields(table_schema) == fields(schema),
+        None => false,
+    }
+}
+
+fn delta_schema(schema: &Schema) -> PolarsResult<Value> {
+    let fields = schema
+        .iter()
+        .map(|(name, dtype)| delta_field(name, dtype))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(json!({ "type": "struct", "fields": fields }))
+}
+
+fn delta_field(name: &str, dtype: &DataType) -> PolarsResult<Value> {
+    Ok(json!({
+        "name": name,
+        "type": delta_type(dtype)?,
+        "nullable": true,
+        "metadata": {},
+    }))
+}
+
+fn delta_type(dtype: &DataType) -> PolarsResult<Value> {
+    use DataType::*;
+    let name = match dtype {
+        Boolean => "boolean",
+        Int8 => "byte",
+        Int16 => "short",
+        Int32 => "integer",
+        Int64 => "long",
+        Float32 => "float",
+        Float64 => "double",
+        Utf8 => "string",
+        #[cfg(feature = "dtype-binary")]
+        Binary => "binary",
+        Date => "date",
+        Datetime(_, _) => "timestamp",
+        List(inner) => {
+            return Ok(json!({
+                "type": "array",
+                "elementType": delta_type(inner)?,
+                "containsNull": true,
+            }))
+        }
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| delta_field(field.name(), field.data_type()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return Ok(json!({ "type": "struct", "fields": fields }));
+        }
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("data type {dt:?} cannot be written to a delta table").into(),
+            ))
+        }
+    };
+    Ok(Value::String(name.to_string()))
+}
+
+/// Delta timestamps have microsecond precision.
+fn to_delta_columns(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Datetime(tu, tz) if *tu != TimeUnit::Microseconds => {
+                s.cast(&DataType::Datetime(TimeUnit::Microseconds, tz.clone()))
+            }
+            _ => Ok(s.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// A random, version 4 formatted, UUID for file names and table ids.
+fn random_uuid() -> String {
+    let random_u64 = || {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or(0),
+        );
+        hasher.finish()
+    };
+    let (hi, lo) = (random_u64(), random_u64());
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        hi >> 32,
+        (hi >> 16) & 0xffff,
+        hi & 0x0fff,
+        ((lo >> 48) & 0x3fff) | 0x8000,
+        lo & 0xffff_ffff_ffff
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::prelude::ParquetReader;
+    use crate::SerReader;
+
+    fn read_table(root: &Path) -> PolarsResult<(Vec<String>, DataFrame)> {
+        let log_dir = root.join(LOG_DIR);
+        let (_, files) = replay_log(&log_dir, &log_versions(&log_dir)?)?;
+        let mut out: Option<DataFrame> = None;
+        for file in &files {
+            let df = ParquetReader::new(File::open(root.join(file))?).finish()?;
+            match out.as_mut() {
+                Some(out) => {
+                    out.vstack_mut(&df)?;
+                }
+                None => out = Some(df),
+            }
+        }
+        Ok((files, out.unwrap()))
+    }
+
+    #[test]
+    fn test_write_delta() -> PolarsResult<()> {
+        let tempdir = TempDir::new("delta-write")?;
+        let root = tempdir.path().join("table");
+        let path = root.to_str().unwrap();
+
+        let df = df!("a" => [1i64, 2], "b" => ["x", "y"])?;
+        df.write_delta(path, DeltaWriteMode::ErrorIfExists)?;
+        assert!(df.write_delta(path, DeltaWriteMode::ErrorIfExists).is_err());
+        assert!(root.join(LOG_DIR).join(format!("{:020}.json", 0)).exists());
+
+        df.write_delta(path, DeltaWriteMode::Append)?;
+        let (files, out) = read_table(&root)?;
+        assert_eq!(files.len(), 2);
+        assert_eq!(out.shape(), (4, 2));
+
+        let other = df!("c" => [1.0f64])?;
+        assert!(other.write_delta(path, DeltaWriteMode::Append).is_err());
+
+        other.write_delta(path, DeltaWriteMode::Overwrite)?;
+        let (files, out) = read_table(&root)?;
+        assert_eq!(files.len(), 1);
+        assert!(out.frame_equal(&other));
+        assert_eq!(log_versions(&root.join(LOG_DIR))?, &[0, 1, 2]);
+
+        let unsigned = df!("a" => [1u32])?;
+        assert!(unsigned
+            .write_delta(path, DeltaWriteMode::Overwrite)
+            .is_err());
+        Ok(())
+    }
+}
@@ -128,6 +128,24 @@ where
     }
 }
 
+/// Write a [`DataFrame`] as newline-delimited JSON.
+pub trait JsonDataFrameExt {
+    /// Write every row as a JSON object `{"col1": val1, "col2": val2, ...}` on its own line.
+    ///
+    /// Nulls are written as `null`, lists as JSON arrays and temporal values as
+    /// ISO 8601 strings.
+    fn write_ndjson<W: Write>(&self, writer: W) -> PolarsResult<()>;
+}
+
+impl JsonDataFrameExt for DataFrame {
+    fn write_ndjson<W: Write>(&self, writer: W) -> PolarsResult<()> {
+        let mut df = self.clone();
+        JsonWriter::new(writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(&mut df)
+    }
+}
+
 #[must_use]
 pub struct JsonReader<R>
 where
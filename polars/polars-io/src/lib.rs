@@ -6,6 +6,8 @@ pub mod avro;
 mod cloud;
 #[cfg(any(feature = "csv-file", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
 #[cfg(feature = "parquet")]
 pub mod export;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
@@ -48,6 +48,7 @@ pub struct ParquetReader<R: Read + Seek> {
     row_count: Option<RowCount>,
     low_memory: bool,
     metadata: Option<FileMetaData>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -120,6 +121,16 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Only keep the rows for which `predicate` evaluates to `true`.
+    ///
+    /// If the predicate can be evaluated on statistics (see [`PhysicalIoExpr::as_stats_evaluator`]),
+    /// the min/max statistics in the `RowGroupMetaData` are used to skip row groups that
+    /// cannot contain matching rows before they are decoded.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
     /// [`Schema`] of the file.
     pub fn schema(&mut self) -> PolarsResult<Schema> {
         let metadata = self.get_metadata()?;
@@ -168,6 +179,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             row_count: None,
             low_memory: false,
             metadata: None,
+            predicate: None,
         }
     }
 
@@ -190,7 +202,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.projection.as_deref(),
             &schema,
             Some(metadata),
-            None,
+            self.predicate,
             self.parallel,
             self.row_count,
         )
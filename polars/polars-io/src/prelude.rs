@@ -3,6 +3,8 @@ use polars_core::prelude::*;
 
 #[cfg(feature = "csv-file")]
 pub use crate::csv::*;
+#[cfg(feature = "delta")]
+pub use crate::delta::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
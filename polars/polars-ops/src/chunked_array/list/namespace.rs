@@ -216,6 +216,53 @@ pub trait ListNameSpaceImpl: AsList {
         Series::try_from((ca.name(), chunks))
     }
 
+    /// Pad or truncate every sublist to exactly `length` items.
+    /// Shorter sublists are extended with `fill_value` (or nulls if `None`),
+    /// longer ones are cut off. Null lists stay null.
+    fn lst_pad_to_length<T>(
+        &self,
+        length: usize,
+        fill_value: Option<T::Native>,
+    ) -> PolarsResult<ListChunked>
+    where
+        T: PolarsNumericType,
+    {
+        let ca = self.as_list();
+        let inner_dtype = ca.inner_dtype();
+        if inner_dtype != T::get_dtype() {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "cannot pad a list of {:?} with a fill value of {:?}",
+                    inner_dtype,
+                    T::get_dtype()
+                )
+                .into(),
+            ));
+        }
+
+        let mut builder = ListPrimitiveChunkedBuilder::<T>::new(
+            ca.name(),
+            ca.len(),
+            ca.len() * length,
+            inner_dtype,
+        );
+        // used to amortize heap allocs
+        let mut buf: Vec<Option<T::Native>> = Vec::with_capacity(length);
+        for opt_s in ca.amortized_iter() {
+            match opt_s {
+                Some(s) => {
+                    let values = s.as_ref().unpack::<T>()?;
+                    buf.clear();
+                    buf.extend(values.into_iter().take(length));
+                    buf.resize(length, fill_value);
+                    builder.append_iter(buf.iter().copied());
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
     #[cfg(feature = "list_take")]
     fn lst_take(&self, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
         let list_ca = self.as_list();
@@ -0,0 +1,103 @@
+use polars_arrow::prelude::ValueSize;
+
+use super::*;
+
+enum Piece<'a> {
+    Literal(&'a str),
+    Column(usize),
+}
+
+/// Split `template` into literal parts and the positions of the `{name}` placeholders.
+fn parse_template<'a>(template: &'a str, names: &[&str]) -> PolarsResult<Vec<Piece<'a>>> {
+    let mut pieces = vec![];
+    let mut found = vec![false; names.len()];
+    let mut literal_start = 0;
+    let mut pos = 0;
+    while let Some(offset) = template[pos..].find('{') {
+        let open = pos + offset;
+        let rest = &template[open + 1..];
+        let placeholder = names.iter().position(|name| {
+            rest.strip_prefix(name)
+                .map_or(false, |rest| rest.starts_with('}'))
+        });
+        match placeholder {
+            Some(i) => {
+                if literal_start < open {
+                    pieces.push(Piece::Literal(&template[literal_start..open]));
+                }
+                pieces.push(Piece::Column(i));
+                found[i] = true;
+                pos = open + names[i].len() + 2;
+                literal_start = pos;
+            }
+            None => pos = open + 1,
+        }
+    }
+    if literal_start < template.len() {
+        pieces.push(Piece::Literal(&template[literal_start..]));
+    }
+    if let Some(i) = found.iter().position(|found| !found) {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "placeholder '{{{}}}' not found in template '{template}'",
+                names[i]
+            )
+            .into(),
+        ));
+    }
+    Ok(pieces)
+}
+
+/// Format every row by replacing the `{name}` placeholders in `template` with the values
+/// of the matching columns. The columns are cast to `Utf8`.
+///
+/// The constant parts of the template are found once up front. A row is null if any of
+/// the columns is null at that row.
+pub fn format_columns(template: &str, cols: &[(&str, &Series)]) -> PolarsResult<Utf8Chunked> {
+    let names = cols.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    let pieces = parse_template(template, &names)?;
+
+    let len = cols.first().map_or(1, |(_, s)| s.len());
+    if cols.iter().any(|(_, s)| s.len() != len) {
+        return Err(PolarsError::ShapeMisMatch(
+            "all columns in 'format_columns' should have the same length".into(),
+        ));
+    }
+    let cols = cols
+        .iter()
+        .map(|(_, s)| s.cast(&DataType::Utf8))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let cas = cols.iter().map(|s| s.utf8().unwrap()).collect::<Vec<_>>();
+
+    let literal_len = pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Literal(lit) => lit.len(),
+            Piece::Column(_) => 0,
+        })
+        .sum::<usize>();
+    let values_len = cas.iter().map(|ca| ca.get_values_size()).sum::<usize>();
+    let name = cas.first().map_or("", |ca| ca.name());
+    let mut builder = Utf8ChunkedBuilder::new(name, len, literal_len * len + values_len);
+
+    let mut iters = cas.iter().map(|ca| ca.into_iter()).collect::<Vec<_>>();
+    let mut row = Vec::with_capacity(iters.len());
+    let mut buf = String::new();
+    for _ in 0..len {
+        row.clear();
+        row.extend(iters.iter_mut().map(|it| it.next().unwrap()));
+        if row.iter().any(|v| v.is_none()) {
+            builder.append_null();
+            continue;
+        }
+        buf.clear();
+        for piece in &pieces {
+            match piece {
+                Piece::Literal(lit) => buf.push_str(lit),
+                Piece::Column(i) => buf.push_str(row[*i].unwrap()),
+            }
+        }
+        builder.append_value(&buf);
+    }
+    Ok(builder.finish())
+}
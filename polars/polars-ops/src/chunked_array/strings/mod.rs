@@ -1,8 +1,12 @@
+#[cfg(feature = "strings")]
+mod format;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
 #[cfg(feature = "strings")]
 mod namespace;
 
+#[cfg(feature = "strings")]
+pub use format::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
@@ -1,30 +1,230 @@
 use std::borrow::Cow;
+use std::net::IpAddr;
 
 #[cfg(feature = "string_encoding")]
-use base64::engine::general_purpose;
+use base64::engine::{general_purpose, GeneralPurpose};
 #[cfg(feature = "string_encoding")]
 use base64::Engine as _;
+#[cfg(feature = "string_url")]
+use percent_encoding::{percent_decode, percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+#[cfg(any(feature = "string_encoding", feature = "string_url"))]
+use polars_arrow::export::arrow::array::{MutableUtf8Array, Utf8Array};
 use polars_arrow::export::arrow::compute::substring::substring;
 use polars_arrow::export::arrow::{self};
 use polars_arrow::kernels::string::*;
+#[cfg(any(feature = "string_encoding", feature = "string_url"))]
+use polars_arrow::prelude::ValueSize;
 use polars_core::export::num::Num;
-use polars_core::export::regex::{escape, Regex};
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::export::regex::{escape, Regex, RegexSetBuilder};
+#[cfg(feature = "string_normalize")]
+use unicode_normalization::UnicodeNormalization;
 
 use super::*;
-#[cfg(feature = "string_encoding")]
+#[cfg(feature = "binary_encoding")]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
 
+/// Unicode normalization form, see [`Utf8NameSpaceImpl::normalize_unicode`].
+#[cfg(feature = "string_normalize")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnicodeNormForm {
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
+}
+
+/// The characters to percent-encode, see [`Utf8NameSpaceImpl::url_encode`].
+#[cfg(feature = "string_url")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UrlEncodeSet {
+    /// A path segment, the URL standard's path percent-encode set.
+    Path,
+    /// A query, the URL standard's query percent-encode set.
+    Query,
+    /// A fragment, the URL standard's fragment percent-encode set.
+    Fragment,
+    /// `application/x-www-form-urlencoded`, which encodes all but ASCII alphanumeric
+    /// characters and `*-._`, and encodes a space as `+`.
+    Form,
+}
+
+// '%' is added to the sets of the URL standard so that decoding restores the input.
+#[cfg(feature = "string_url")]
+const FRAGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'%');
+#[cfg(feature = "string_url")]
+const QUERY: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'%');
+#[cfg(feature = "string_url")]
+const PATH: &AsciiSet = &QUERY.add(b'?').add(b'`').add(b'{').add(b'}');
+#[cfg(feature = "string_url")]
+const FORM: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'*')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_');
+
+static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?)+$",
+    )
+    .unwrap()
+});
+
+static URL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?i:https?|ftp)://[^\s/?#]+(?:[/?#]\S*)?$").unwrap());
+
+fn str_predicate<F>(ca: &Utf8Chunked, f: F) -> BooleanChunked
+where
+    F: Fn(&str) -> bool,
+{
+    let mut out: BooleanChunked = if !ca.has_validity() {
+        ca.into_no_null_iter().map(f).collect()
+    } else {
+        ca.into_iter().map(|opt_s| opt_s.map(&f)).collect()
+    };
+    out.rename(ca.name());
+    out
+}
+
 fn f_regex_extract<'a>(reg: &Regex, input: &'a str, group_index: usize) -> Option<Cow<'a, str>> {
     reg.captures(input)
         .and_then(|cap| cap.get(group_index).map(|m| Cow::Borrowed(m.as_str())))
 }
 
+fn binary_str_predicate<F>(
+    ca: &Utf8Chunked,
+    other: &Utf8Chunked,
+    f: F,
+) -> PolarsResult<BooleanChunked>
+where
+    F: Fn(&str, &str) -> bool,
+{
+    if ca.len() != other.len() {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "expected a series of length {}, got length {}",
+                ca.len(),
+                other.len()
+            )
+            .into(),
+        ));
+    }
+    let mut out: BooleanChunked = ca
+        .into_iter()
+        .zip(other)
+        .map(|(opt_s, opt_sub)| match (opt_s, opt_sub) {
+            (Some(s), Some(sub)) => Some(f(s, sub)),
+            _ => None,
+        })
+        .collect();
+    out.rename(ca.name());
+    Ok(out)
+}
+
+/// Map every string by writing the new value into a buffer that is reused for all strings
+/// of a chunk. `values_capacity` estimates the size of the new values of a chunk.
+#[cfg(any(feature = "string_encoding", feature = "string_url"))]
+fn map_with_buffer<C, F>(
+    ca: &Utf8Chunked,
+    values_capacity: C,
+    mut f: F,
+) -> PolarsResult<Utf8Chunked>
+where
+    C: Fn(usize) -> usize,
+    F: FnMut(&str, &mut Vec<u8>) -> PolarsResult<()>,
+{
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let mut out = MutableUtf8Array::<i64>::with_capacities(
+                arr.len(),
+                values_capacity(arr.get_values_size()),
+            );
+            let mut buf = vec![];
+            for opt_s in arr {
+                match opt_s {
+                    Some(s) => {
+                        buf.clear();
+                        f(s, &mut buf)?;
+                        let v = std::str::from_utf8(&buf).map_err(|_| {
+                            PolarsError::ComputeError(
+                                format!("'{s}' does not decode to valid utf8").into(),
+                            )
+                        })?;
+                        out.push(Some(v))
+                    }
+                    None => out.push::<&str>(None),
+                }
+            }
+            let arr: Utf8Array<i64> = out.into();
+            Ok(Box::new(arr) as ArrayRef)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    unsafe { Ok(Utf8Chunked::from_chunks(ca.name(), chunks)) }
+}
+
+#[cfg(feature = "string_encoding")]
+fn base64_encode_impl(ca: &Utf8Chunked, engine: &GeneralPurpose) -> Utf8Chunked {
+    map_with_buffer(
+        ca,
+        // every 3 bytes are encoded as 4 characters
+        |values_size| (values_size + 2) / 3 * 4,
+        |s, buf| {
+            buf.resize(base64::encoded_len(s.len(), true).unwrap(), 0);
+            engine.encode_slice(s, buf).unwrap();
+            Ok(())
+        },
+    )
+    .unwrap()
+}
+
+#[cfg(feature = "string_encoding")]
+fn base64_decode_impl(ca: &Utf8Chunked, engine: &GeneralPurpose) -> PolarsResult<Utf8Chunked> {
+    map_with_buffer(
+        ca,
+        |values_size| values_size / 4 * 3,
+        |s, buf| {
+            engine.decode_vec(s, buf).map_err(|_| {
+                PolarsError::ComputeError(format!("invalid base64 encoded string '{s}'").into())
+            })
+        },
+    )
+}
+
 pub trait Utf8NameSpaceImpl: AsUtf8 {
     #[cfg(not(feature = "binary_encoding"))]
     fn hex_decode(&self) -> PolarsResult<Utf8Chunked> {
         panic!("activate 'dtype-binary' feature")
     }
 
+    /// Decode the hex encoded strings. Returns an error if a string is not valid hex or
+    /// does not decode to valid UTF-8.
+    #[cfg(feature = "string_encoding")]
+    fn hex_decode_utf8(&self) -> PolarsResult<Utf8Chunked> {
+        map_with_buffer(
+            self.as_utf8(),
+            |values_size| values_size / 2,
+            |s, buf| {
+                buf.resize(s.len() / 2, 0);
+                hex::decode_to_slice(s, buf).map_err(|_| {
+                    PolarsError::ComputeError(format!("invalid hex encoded string '{s}'").into())
+                })
+            },
+        )
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_utf8();
@@ -36,8 +236,16 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
     #[must_use]
     #[cfg(feature = "string_encoding")]
     fn hex_encode(&self) -> Utf8Chunked {
-        let ca = self.as_utf8();
-        ca.apply(|s| hex::encode(s).into())
+        map_with_buffer(
+            self.as_utf8(),
+            |values_size| values_size * 2,
+            |s, buf| {
+                buf.resize(s.len() * 2, 0);
+                hex::encode_to_slice(s, buf).unwrap();
+                Ok(())
+            },
+        )
+        .unwrap()
     }
 
     #[cfg(not(feature = "binary_encoding"))]
@@ -45,6 +253,13 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         panic!("activate 'dtype-binary' feature")
     }
 
+    /// Decode the strings with the standard Base64 alphabet. Returns an error if a string is
+    /// not valid Base64 or does not decode to valid UTF-8.
+    #[cfg(feature = "string_encoding")]
+    fn base64_decode_utf8(&self) -> PolarsResult<Utf8Chunked> {
+        base64_decode_impl(self.as_utf8(), &general_purpose::STANDARD)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn base64_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_utf8();
@@ -53,11 +268,88 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
             .base64_decode(strict)
     }
 
+    /// Decode the strings with the URL-safe Base64 alphabet. Returns an error if a string is
+    /// not valid Base64 or does not decode to valid UTF-8.
+    #[cfg(feature = "string_encoding")]
+    fn base64_decode_url_safe_utf8(&self) -> PolarsResult<Utf8Chunked> {
+        base64_decode_impl(self.as_utf8(), &general_purpose::URL_SAFE)
+    }
+
+    /// Encode the strings with the standard Base64 alphabet.
     #[must_use]
     #[cfg(feature = "string_encoding")]
     fn base64_encode(&self) -> Utf8Chunked {
-        let ca = self.as_utf8();
-        ca.apply(|s| general_purpose::STANDARD.encode(s).into())
+        base64_encode_impl(self.as_utf8(), &general_purpose::STANDARD)
+    }
+
+    /// Encode the strings with the URL-safe Base64 alphabet.
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn base64_encode_url_safe(&self) -> Utf8Chunked {
+        base64_encode_impl(self.as_utf8(), &general_purpose::URL_SAFE)
+    }
+
+    /// Percent-encode the characters of `set`; characters outside of ASCII are always encoded
+    /// as the percent-encoded bytes of their UTF-8 representation.
+    #[must_use]
+    #[cfg(feature = "string_url")]
+    fn url_encode(&self, set: UrlEncodeSet) -> Utf8Chunked {
+        let ascii_set = match set {
+            UrlEncodeSet::Path => PATH,
+            UrlEncodeSet::Query => QUERY,
+            UrlEncodeSet::Fragment => FRAGMENT,
+            UrlEncodeSet::Form => FORM,
+        };
+        map_with_buffer(
+            self.as_utf8(),
+            |values_size| values_size,
+            |s, buf| {
+                for part in percent_encode(s.as_bytes(), ascii_set) {
+                    if set == UrlEncodeSet::Form && part == "%20" {
+                        buf.push(b'+')
+                    } else {
+                        buf.extend_from_slice(part.as_bytes())
+                    }
+                }
+                Ok(())
+            },
+        )
+        .unwrap()
+    }
+
+    /// Decode percent-encoded strings, which for [`UrlEncodeSet::Form`] also decodes `+` as a
+    /// space. Returns an error if a `%` is not followed by two hex digits or if a string does
+    /// not decode to valid UTF-8.
+    #[cfg(feature = "string_url")]
+    fn url_decode(&self, set: UrlEncodeSet) -> PolarsResult<Utf8Chunked> {
+        map_with_buffer(
+            self.as_utf8(),
+            |values_size| values_size,
+            |s, buf| {
+                let bytes = s.as_bytes();
+                let malformed = bytes.iter().enumerate().any(|(i, b)| {
+                    *b == b'%'
+                        && !bytes
+                            .get(i + 1..i + 3)
+                            .map_or(false, |hex| hex.iter().all(u8::is_ascii_hexdigit))
+                });
+                if malformed {
+                    return Err(PolarsError::ComputeError(
+                        format!("invalid percent-encoded string '{s}'").into(),
+                    ));
+                }
+                if set == UrlEncodeSet::Form {
+                    let replaced = bytes
+                        .iter()
+                        .map(|b| if *b == b'+' { b' ' } else { *b })
+                        .collect::<Vec<_>>();
+                    buf.extend(percent_decode(&replaced));
+                } else {
+                    buf.extend(percent_decode(bytes));
+                }
+                Ok(())
+            },
+        )
     }
 
     #[cfg(feature = "string_from_radix")]
@@ -90,29 +382,51 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         let ca = self.as_utf8();
 
         let f = |s: &'a str| {
-            let alignment = alignment.saturating_sub(s.len());
-            if alignment == 0 {
+            let padding = alignment.saturating_sub(s.len());
+            if padding == 0 {
                 return Cow::Borrowed(s);
             }
-            if let Some(stripped) = s.strip_prefix('-') {
-                Cow::Owned(format!(
-                    "-{:0alignment$}{value}",
-                    0,
-                    alignment = alignment,
-                    value = stripped
-                ))
+            let (sign, digits) = if s.starts_with(['-', '+']) {
+                s.split_at(1)
             } else {
-                Cow::Owned(format!(
-                    "{:0alignment$}{value}",
-                    0,
-                    alignment = alignment,
-                    value = s
-                ))
+                ("", s)
+            };
+            let mut buf = String::with_capacity(alignment);
+            buf.push_str(sign);
+            for _ in 0..padding {
+                buf.push('0')
             }
+            buf.push_str(digits);
+            Cow::Owned(buf)
         };
         ca.apply(f)
     }
 
+    /// Normalize the strings to the given unicode normalization `form`.
+    #[cfg(feature = "string_normalize")]
+    fn normalize_unicode(&self, form: UnicodeNormForm) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        let mut builder = Utf8ChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+        // reused for every string
+        let mut buf = String::new();
+        for opt_s in ca {
+            match opt_s {
+                Some(s) => {
+                    buf.clear();
+                    match form {
+                        UnicodeNormForm::NFC => buf.extend(s.nfc()),
+                        UnicodeNormForm::NFD => buf.extend(s.nfd()),
+                        UnicodeNormForm::NFKC => buf.extend(s.nfkc()),
+                        UnicodeNormForm::NFKD => buf.extend(s.nfkd()),
+                    }
+                    builder.append_value(&buf)
+                }
+                None => builder.append_null(),
+            }
+        }
+        builder.finish()
+    }
+
     /// Return the string left justified in a string of length width.
     /// Padding is done using the specified `fillchar`,
     /// The original string is returned if width is less than or equal to `s.len()`.
@@ -181,11 +495,39 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(out)
     }
 
+    /// Check if the strings are email addresses of the form `local@domain.tld`.
+    fn is_email(&self) -> BooleanChunked {
+        str_predicate(self.as_utf8(), |s| EMAIL_RE.is_match(s))
+    }
+
+    /// Check if the strings are absolute `http`, `https` or `ftp` URLs.
+    fn is_url(&self) -> BooleanChunked {
+        str_predicate(self.as_utf8(), |s| URL_RE.is_match(s))
+    }
+
+    /// Check if the strings are IPv4 or IPv6 addresses.
+    fn is_ip_address(&self) -> BooleanChunked {
+        str_predicate(self.as_utf8(), |s| s.parse::<IpAddr>().is_ok())
+    }
+
     /// Check if strings contain a given literal
     fn contains_literal(&self, lit: &str) -> PolarsResult<BooleanChunked> {
         self.contains(escape(lit).as_str())
     }
 
+    /// Check if the strings match at least one of the regex `patterns`. The patterns are
+    /// compiled once into a single regex set.
+    fn contains_any(
+        &self,
+        patterns: &[&str],
+        case_insensitive: bool,
+    ) -> PolarsResult<BooleanChunked> {
+        let set = RegexSetBuilder::new(patterns)
+            .case_insensitive(case_insensitive)
+            .build()?;
+        Ok(str_predicate(self.as_utf8(), |s| set.is_match(s)))
+    }
+
     /// Check if strings ends with a substring
     fn ends_with(&self, sub: &str) -> BooleanChunked {
         let ca = self.as_utf8();
@@ -204,6 +546,16 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         out
     }
 
+    /// Check if strings ends with the substring at the same position in `suffixes`.
+    fn ends_with_series(&self, suffixes: &Utf8Chunked) -> PolarsResult<BooleanChunked> {
+        binary_str_predicate(self.as_utf8(), suffixes, |s, sub| s.ends_with(sub))
+    }
+
+    /// Check if strings starts with the substring at the same position in `prefixes`.
+    fn starts_with_series(&self, prefixes: &Utf8Chunked) -> PolarsResult<BooleanChunked> {
+        binary_str_predicate(self.as_utf8(), prefixes, |s, sub| s.starts_with(sub))
+    }
+
     /// Replace the leftmost regex-matched (sub)string with another string; take
     /// fast-path for small (<= 32 chars) strings (otherwise regex faster).
     fn replace<'a>(&'a self, pat: &str, val: &str) -> PolarsResult<Utf8Chunked> {
@@ -296,6 +648,44 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(builder.finish())
     }
 
+    /// Split every string by `delimiter` and return the first `n` parts as `Utf8` columns
+    /// named `names`. Parts that a string doesn't have are null, and so are all parts of a
+    /// null string.
+    fn split_to_columns(
+        &self,
+        delimiter: &str,
+        n: usize,
+        names: &[&str],
+    ) -> PolarsResult<DataFrame> {
+        if names.len() != n {
+            return Err(PolarsError::ShapeMisMatch(
+                format!("expected {n} column names, got {}", names.len()).into(),
+            ));
+        }
+        let ca = self.as_utf8();
+        let mut builders = names
+            .iter()
+            .map(|name| Utf8ChunkedBuilder::new(name, ca.len(), ca.get_values_size() / n.max(1)))
+            .collect::<Vec<_>>();
+
+        for opt_s in ca.into_iter() {
+            match opt_s {
+                None => builders.iter_mut().for_each(|b| b.append_null()),
+                Some(s) => {
+                    let mut parts = s.split(delimiter);
+                    for b in builders.iter_mut() {
+                        b.append_option(parts.next());
+                    }
+                }
+            }
+        }
+        let columns = builders
+            .into_iter()
+            .map(|b| b.finish().into_series())
+            .collect();
+        DataFrame::new(columns)
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_match(&self, pat: &str) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_utf8();
@@ -330,6 +720,14 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         ca + other
     }
 
+    /// Replace every `{col_name}` placeholder in `template` with the string values.
+    ///
+    /// See [`format_columns`] to format multiple columns at once.
+    fn format_with_template(&self, template: &str, col_name: &str) -> PolarsResult<Utf8Chunked> {
+        let s = self.as_utf8().clone().into_series();
+        format_columns(template, &[(col_name, &s)])
+    }
+
     /// Slice the string values
     /// Determines a substring starting from `start` and with optional length `length` of each of the elements in `array`.
     /// `start` can be negative, in which case the start counts from the end of the string.
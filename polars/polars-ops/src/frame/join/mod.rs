@@ -435,6 +435,51 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinType::Outer, None)
     }
+
+    /// Keep the rows of `self` that have a match in `other`. Only the keys of `other` are
+    /// hashed and the columns of `other` are not added, so no joined data is materialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.semi_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn semi_join<I, S>(&self, other: &DataFrame, left_on: I, right_on: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinType::Semi, None)
+    }
+
+    /// Keep the rows of `self` that have no match in `other`, the complement of
+    /// [`DataFrameJoinOps::semi_join`].
+    #[cfg(feature = "semi_anti_join")]
+    fn anti_join<I, S>(&self, other: &DataFrame, left_on: I, right_on: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinType::Anti, None)
+    }
+
+    /// Keep the rows of `self` for which at least one row in `reference` has equal values in
+    /// `right_cols` as `self` has in `left_cols`. This is a [`DataFrameJoinOps::semi_join`]:
+    /// the keys of `reference` are hashed and every row of `self` is probed, so matches
+    /// are never expanded into a cartesian product.
+    #[cfg(feature = "semi_anti_join")]
+    fn cartesian_filter(
+        &self,
+        reference: &DataFrame,
+        left_cols: &[&str],
+        right_cols: &[&str],
+    ) -> PolarsResult<DataFrame> {
+        self.semi_join(reference, left_cols, right_cols)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -145,6 +145,64 @@ where
     )
 }
 
+/// Pivot `values` into a `DataFrame` of `n_rows` by `n_cols` using pre-computed row and
+/// column indices, skipping the groupby and aggregation of [`pivot`].
+///
+/// Value `i` is written to row `row_idx[i]` of column `col_idx[i]`. If multiple values
+/// land on the same cell, the last one wins. Cells that receive no value are null, and
+/// entries with a null row or column index are ignored.
+pub fn pivot_from_indices(
+    values: &Series,
+    row_idx: &UInt32Chunked,
+    col_idx: &UInt32Chunked,
+    n_rows: usize,
+    n_cols: usize,
+    col_names: &[&str],
+) -> PolarsResult<DataFrame> {
+    if values.len() != row_idx.len() || values.len() != col_idx.len() {
+        return Err(PolarsError::ShapeMisMatch(
+            "values, row_idx and col_idx should have the same length".into(),
+        ));
+    }
+    if col_names.len() != n_cols {
+        return Err(PolarsError::ShapeMisMatch(
+            format!("expected {} column names, got {}", n_cols, col_names.len()).into(),
+        ));
+    }
+
+    // for every cell, the location in `values` that was written last
+    let mut locations: Vec<Option<IdxSize>> = vec![None; n_rows * n_cols];
+    for (i, (opt_row, opt_col)) in row_idx.into_iter().zip(col_idx).enumerate() {
+        if let (Some(row), Some(col)) = (opt_row, opt_col) {
+            let (row, col) = (row as usize, col as usize);
+            if row >= n_rows || col >= n_cols {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "index ({row}, {col}) is out of bounds for a pivot of shape ({n_rows}, {n_cols})"
+                    )
+                    .into(),
+                ));
+            }
+            locations[row + col * n_rows] = Some(i as IdxSize);
+        }
+    }
+
+    let columns = POOL.install(|| {
+        col_names
+            .par_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let offset = i * n_rows;
+                let idx = IdxCa::from_slice_options("", &locations[offset..offset + n_rows]);
+                let mut out = values.take(&idx)?;
+                out.rename(name);
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    DataFrame::new(columns)
+}
+
 fn pivot_impl(
     pivot_df: &DataFrame,
     // these columns will be aggregated in the nested groupby
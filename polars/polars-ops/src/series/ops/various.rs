@@ -5,6 +5,10 @@ use polars_core::series::IsSorted;
 
 use crate::series::ops::SeriesSealed;
 
+/// The hash that [`SeriesMethods::hash_array`] assigns to null values.
+#[cfg(feature = "hash")]
+pub const NULL_HASH: u64 = u64::MAX;
+
 pub trait SeriesMethods: SeriesSealed {
     /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
     /// with dtype [`IdxType`]
@@ -39,6 +43,25 @@ pub trait SeriesMethods: SeriesSealed {
         }
     }
 
+    /// Hash every value to a `u64` with a hasher seeded by `seed`.
+    ///
+    /// In contrast to [`SeriesMethods::hash`], null values always hash to [`NULL_HASH`],
+    /// independent of the seed, so null keys can be recognized after hashing.
+    #[cfg(feature = "hash")]
+    fn hash_array(&self, seed: u64) -> UInt64Chunked {
+        let s = self.as_series();
+        let hashes = self.hash(ahash::RandomState::with_seeds(seed, seed, seed, seed));
+        if s.null_count() == 0 {
+            return hashes;
+        }
+        let hashes = hashes
+            .into_no_null_iter()
+            .zip(s.is_not_null().into_no_null_iter())
+            .map(|(h, valid)| if valid { h } else { NULL_HASH })
+            .collect::<Vec<_>>();
+        UInt64Chunked::from_vec(s.name(), hashes)
+    }
+
     fn is_sorted(&self, options: SortOptions) -> bool {
         let s = self.as_series();
 
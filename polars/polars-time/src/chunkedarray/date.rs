@@ -1,4 +1,7 @@
-use polars_arrow::export::arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+use chrono::Months;
+use polars_arrow::export::arrow::temporal_conversions::{
+    date32_to_date, MILLISECONDS, SECONDS_IN_DAY,
+};
 
 use super::*;
 
@@ -73,6 +76,41 @@ pub trait DateMethods: AsDate {
         ca.apply_kernel_cast::<UInt32Type>(&date_to_ordinal)
     }
 
+    /// Shift every date by a number of calendar `months`, which may be negative. If the
+    /// day doesn't exist in the resulting month, the last day of that month is used,
+    /// e.g. 2022-01-31 plus one month is 2022-02-28.
+    ///
+    /// Returns an error if a resulting date is out of range.
+    fn offset_months(&self, months: i32) -> PolarsResult<DateChunked> {
+        let ca = self.as_date();
+        let offset = Months::new(months.unsigned_abs());
+        let out = ca.0.try_apply(|v| {
+            let date = date32_to_date(v);
+            let shifted = if months >= 0 {
+                date.checked_add_months(offset)
+            } else {
+                date.checked_sub_months(offset)
+            };
+            shifted.map(naive_date_to_date).ok_or_else(|| {
+                PolarsError::ComputeError(
+                    format!("offsetting date {date} by {months} months is out of range").into(),
+                )
+            })
+        })?;
+        Ok(out.into_date())
+    }
+
+    /// Shift every date by a number of calendar `years`, which may be negative. February
+    /// 29 maps to February 28 in years that are not leap years.
+    ///
+    /// Returns an error if a resulting date is out of range.
+    fn offset_years(&self, years: i32) -> PolarsResult<DateChunked> {
+        let months = years.checked_mul(12).ok_or_else(|| {
+            PolarsError::ComputeError(format!("offset of {years} years is out of range").into())
+        })?;
+        self.offset_months(months)
+    }
+
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> DateChunked;
 }
 
@@ -91,6 +129,65 @@ impl DateMethods for DateChunked {
     }
 }
 
+/// Number of business days (Monday to Friday) from the epoch-aligned Monday before
+/// 1970-01-01 up to (but not including) `date`.
+fn business_days_before(date: i32) -> i64 {
+    // 1970-01-01 was a thursday, so shift such that mondays are multiples of 7
+    let shifted = date as i64 + 3;
+    shifted.div_euclid(7) * 5 + shifted.rem_euclid(7).min(5)
+}
+
+fn is_business_day(date: i32) -> bool {
+    (date as i64 + 3).rem_euclid(7) < 5
+}
+
+/// Count the business days (Monday to Friday) in the half-open interval `[start, end)`,
+/// element-wise. If `end` lies before `start` the count is negative.
+///
+/// Dates in `holidays` are not counted as business days. Null values in either date
+/// column produce a null.
+pub fn business_day_count(
+    start: &DateChunked,
+    end: &DateChunked,
+    holidays: &[i32],
+) -> PolarsResult<Int32Chunked> {
+    if start.len() != end.len() {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "cannot count business days between date columns of length {} and {}",
+                start.len(),
+                end.len()
+            )
+            .into(),
+        ));
+    }
+    // only holidays on business days reduce the count
+    let mut holidays = holidays
+        .iter()
+        .copied()
+        .filter(|d| is_business_day(*d))
+        .collect::<Vec<_>>();
+    holidays.sort_unstable();
+    holidays.dedup();
+    let holidays_before = |date: i32| holidays.partition_point(|h| *h < date) as i64;
+
+    let mut out: Int32Chunked = start
+        .into_iter()
+        .zip(end.into_iter())
+        .map(|(start, end)| match (start, end) {
+            (Some(start), Some(end)) => {
+                let count = business_days_before(end)
+                    - business_days_before(start)
+                    - (holidays_before(end) - holidays_before(start));
+                Some(count as i32)
+            }
+            _ => None,
+        })
+        .collect_trusted();
+    out.rename(start.name());
+    Ok(out)
+}
+
 pub trait AsDate {
     fn as_date(&self) -> &DateChunked;
 }
@@ -100,3 +197,80 @@ impl AsDate for DateChunked {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_business_day_count() -> PolarsResult<()> {
+        let date = |s| naive_date_to_date(NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap());
+        // 2023-01-02 is a monday
+        let start = Int32Chunked::from_slice_options(
+            "start",
+            &[
+                Some(date("2023-01-02")),
+                Some(date("2023-01-06")),
+                Some(date("2023-01-16")),
+                None,
+            ],
+        )
+        .into_date();
+        let end = Int32Chunked::from_slice_options(
+            "end",
+            &[
+                Some(date("2023-01-09")),
+                Some(date("2023-01-09")),
+                Some(date("2023-01-02")),
+                Some(date("2023-01-09")),
+            ],
+        )
+        .into_date();
+
+        let out = business_day_count(&start, &end, &[])?;
+        assert_eq!(Vec::from(&out), &[Some(5), Some(1), Some(-10), None]);
+
+        // a holiday on a sunday does not change the count
+        let holidays = [date("2023-01-03"), date("2023-01-08")];
+        let out = business_day_count(&start, &end, &holidays)?;
+        assert_eq!(Vec::from(&out), &[Some(4), Some(1), Some(-9), None]);
+
+        assert!(business_day_count(&start, &end.slice(0, 2).into_date(), &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_offset_months() -> PolarsResult<()> {
+        let date = |s| naive_date_to_date(NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap());
+        let ca = Int32Chunked::from_slice_options(
+            "a",
+            &[Some(date("2022-01-31")), None, Some(date("2020-02-29"))],
+        )
+        .into_date();
+
+        let fmt = |ca: DateChunked| {
+            Vec::from(&ca.strftime("%Y-%m-%d"))
+                .into_iter()
+                .map(|s| s.map(|s| s.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let expected = |a: &str, b: &str| vec![Some(a.to_string()), None, Some(b.to_string())];
+
+        assert_eq!(
+            fmt(ca.offset_months(1)?),
+            expected("2022-02-28", "2020-03-29")
+        );
+        assert_eq!(
+            fmt(ca.offset_months(-2)?),
+            expected("2021-11-30", "2019-12-29")
+        );
+        assert_eq!(
+            fmt(ca.offset_years(1)?),
+            expected("2023-01-31", "2021-02-28")
+        );
+        assert_eq!(ca.offset_months(0)?.name(), "a");
+
+        assert!(ca.offset_years(300_000).is_err());
+        Ok(())
+    }
+}
@@ -28,6 +28,30 @@ pub trait DurationMethods {
 
     /// Extract the nanoseconds from a `Duration`
     fn nanoseconds(&self) -> Int64Chunked;
+
+    /// The total length of a `Duration` in seconds, including the fractional part
+    fn total_seconds(&self) -> Float64Chunked;
+
+    /// The total length of a `Duration` in milliseconds, including the fractional part
+    fn total_milliseconds(&self) -> Float64Chunked;
+
+    /// The total length of a `Duration` in microseconds, including the fractional part
+    fn total_microseconds(&self) -> Float64Chunked;
+
+    /// The total length of a `Duration` in nanoseconds, which is always a whole number
+    fn total_nanoseconds(&self) -> Int64Chunked;
+}
+
+/// Convert a `Duration` to a fractional number of units, where a second has
+/// `units_per_second` units.
+fn total_in_units(ca: &DurationChunked, units_per_second: i64) -> Float64Chunked {
+    let own_units_per_second = match ca.time_unit() {
+        TimeUnit::Milliseconds => MILLISECONDS,
+        TimeUnit::Microseconds => MICROSECONDS,
+        TimeUnit::Nanoseconds => NANOSECONDS,
+    };
+    let factor = units_per_second as f64 / own_units_per_second as f64;
+    ca.0.apply_cast_numeric(|v| v as f64 * factor)
 }
 
 impl DurationMethods for DurationChunked {
@@ -93,4 +117,20 @@ impl DurationMethods for DurationChunked {
             TimeUnit::Nanoseconds => self.0.clone(),
         }
     }
+
+    fn total_seconds(&self) -> Float64Chunked {
+        total_in_units(self, 1)
+    }
+
+    fn total_milliseconds(&self) -> Float64Chunked {
+        total_in_units(self, MILLISECONDS)
+    }
+
+    fn total_microseconds(&self) -> Float64Chunked {
+        total_in_units(self, MICROSECONDS)
+    }
+
+    fn total_nanoseconds(&self) -> Int64Chunked {
+        self.nanoseconds()
+    }
 }
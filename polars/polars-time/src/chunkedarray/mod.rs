@@ -13,7 +13,7 @@ pub mod utf8;
 
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "dtype-date")]
-pub use date::DateMethods;
+pub use date::{business_day_count, DateMethods};
 #[cfg(feature = "dtype-datetime")]
 pub use datetime::DatetimeMethods;
 #[cfg(feature = "dtype-duration")]
@@ -23,7 +23,8 @@ where
     /// Apply a rolling mean (moving mean) over the values in this array.
     /// A window of length `window_size` will traverse the array. The values that fill this window
     /// will (optionally) be multiplied with the weights given by the `weights` vector. The resulting
-    /// values will be aggregated to their mean.
+    /// values will be aggregated to their mean, where the weights are normalized to sum to one
+    /// over the non-null values in the window.
     fn rolling_mean(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         rolling_agg(
             &self.0,
@@ -381,6 +381,35 @@ pub trait Utf8Methods: AsUtf8 {
         Ok(ca.into())
     }
 
+    #[cfg(feature = "dtype-date")]
+    /// Parse string values to a [`DateChunked`].
+    ///
+    /// If `fmt` is `None`, the format is inferred by trying a list of common date formats
+    /// (ISO 8601, `%m/%d/%Y`, `%d-%m-%Y`, ...) on the first non-null value. The winning
+    /// format is then applied to all values. Values that can't be parsed become null.
+    fn parse_dates(&self, fmt: Option<&str>) -> PolarsResult<DateChunked> {
+        let utf8_ca = self.as_utf8();
+        let fmt = match fmt {
+            Some(fmt) => fmt,
+            None => {
+                if utf8_ca.first_non_null().is_none() {
+                    return Ok(Int32Chunked::full_null(utf8_ca.name(), utf8_ca.len()).into_date());
+                }
+                let val = get_first_val(utf8_ca)?;
+                patterns::DATE_COMMON
+                    .iter()
+                    .copied()
+                    .find(|fmt| NaiveDate::parse_from_str(val, fmt).is_ok())
+                    .ok_or_else(|| {
+                        PolarsError::ComputeError(
+                            format!("Could not find an appropriate format to parse date '{val}', please define a fmt").into(),
+                        )
+                    })?
+            }
+        };
+        self.as_date(Some(fmt), true)
+    }
+
     #[cfg(feature = "dtype-datetime")]
     /// Parsing string values and return a [`DatetimeChunked`]
     fn as_datetime(
@@ -18,6 +18,27 @@ pub(super) static DATE_Y_M_D: &[&str] = &[
     "%y_%m_%d",
 ];
 
+/// Formats that are tried in order on the first value to infer the format of a date
+/// column in `Utf8Methods::parse_dates`.
+pub(super) static DATE_COMMON: &[&str] = &[
+    // 2021-12-31
+    "%Y-%m-%d",
+    // 2021/12/31
+    "%Y/%m/%d",
+    // 20211231
+    "%Y%m%d",
+    // 12/31/2021
+    "%m/%d/%Y",
+    // 31-12-2021
+    "%d-%m-%Y",
+    // 31.12.2021
+    "%d.%m.%Y",
+    // 31 Dec 2021
+    "%d %b %Y",
+    // Dec 31, 2021
+    "%b %d, %Y",
+];
+
 /// NOTE: don't use single letter dates like %F
 /// polars parsers does not support them, so it will be slower
 pub(super) static DATETIME_D_M_Y: &[&str] = &[
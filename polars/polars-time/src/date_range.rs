@@ -1,4 +1,8 @@
+#[cfg(feature = "dtype-date")]
+use chrono::NaiveDate;
 use chrono::{Datelike, NaiveDateTime};
+#[cfg(feature = "dtype-date")]
+use polars_arrow::export::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
@@ -49,10 +53,137 @@ pub fn date_range(
     let (start, stop) = match tu {
         TimeUnit::Nanoseconds => (start.timestamp_nanos(), stop.timestamp_nanos()),
         TimeUnit::Microseconds => (
-            start.timestamp() + start.timestamp_subsec_micros() as i64,
-            stop.timestamp() + stop.timestamp_subsec_millis() as i64,
+            start.timestamp() * 1_000_000 + start.timestamp_subsec_micros() as i64,
+            stop.timestamp() * 1_000_000 + stop.timestamp_subsec_micros() as i64,
         ),
         TimeUnit::Milliseconds => (start.timestamp_millis(), stop.timestamp_millis()),
     };
     date_range_impl(name, start, stop, every, closed, tu, tz.as_ref())
 }
+
+/// Create a [`DateChunked`] from a given `start` and `stop` date and a given `every` interval.
+///
+/// Returns an error if `every` is not positive or is not a whole number of days, or if
+/// `start` is after `stop`.
+#[cfg(feature = "dtype-date")]
+pub fn naive_date_range(
+    name: &str,
+    start: NaiveDate,
+    stop: NaiveDate,
+    every: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<DateChunked> {
+    const NANOSECONDS_IN_DAY: i64 = 86_400_000_000_000;
+    if every.is_zero() || every.negative {
+        return Err(PolarsError::ComputeError(
+            "'every' in 'naive_date_range' must be positive".into(),
+        ));
+    }
+    if start > stop {
+        return Err(PolarsError::ComputeError(
+            format!("'start' {start} in 'naive_date_range' is after 'stop' {stop}").into(),
+        ));
+    }
+    if every.nanoseconds() % NANOSECONDS_IN_DAY != 0 {
+        return Err(PolarsError::ComputeError(
+            "'every' in 'naive_date_range' must be a whole number of days".into(),
+        ));
+    }
+    let to_ms = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap().timestamp_millis();
+    let (start, stop) = (to_ms(start), to_ms(stop));
+    let days = date_range_vec(start, stop, every, closed, TimeUnit::Milliseconds)
+        .into_iter()
+        .map(|ms| (ms / MILLISECONDS_IN_DAY) as i32)
+        .collect();
+
+    let mut out = Int32Chunked::new_vec(name, days).into_date();
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_date_range_microseconds() -> PolarsResult<()> {
+        let start = NaiveDateTime::from_timestamp_opt(1, 500_000).unwrap();
+        let stop = NaiveDateTime::from_timestamp_opt(3, 500_000).unwrap();
+        let out = date_range(
+            "a",
+            start,
+            stop,
+            Duration::parse("1s"),
+            ClosedWindow::Left,
+            TimeUnit::Microseconds,
+            None,
+        )?;
+        assert_eq!(Vec::from(&out.0), &[Some(1_000_500), Some(2_000_500)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_naive_date_range() -> PolarsResult<()> {
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let out = naive_date_range(
+            "a",
+            date("2022-01-30"),
+            date("2022-02-02"),
+            Duration::parse("1d"),
+            ClosedWindow::Left,
+        )?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out.strftime("%Y-%m-%d")),
+            &[Some("2022-01-30"), Some("2022-01-31"), Some("2022-02-01")]
+        );
+
+        let out = naive_date_range(
+            "a",
+            date("2022-01-31"),
+            date("2022-04-30"),
+            Duration::parse("1mo"),
+            ClosedWindow::Both,
+        )?;
+        assert_eq!(out.len(), 4);
+
+        let every = Duration::parse("12h");
+        assert!(naive_date_range(
+            "a",
+            date("2022-01-01"),
+            date("2022-01-02"),
+            every,
+            ClosedWindow::Both
+        )
+        .is_err());
+        let every = Duration::parse("0d");
+        assert!(naive_date_range(
+            "a",
+            date("2022-01-01"),
+            date("2022-01-02"),
+            every,
+            ClosedWindow::Both
+        )
+        .is_err());
+        let every = Duration::parse("-1d");
+        assert!(naive_date_range(
+            "a",
+            date("2022-01-01"),
+            date("2022-01-02"),
+            every,
+            ClosedWindow::Both
+        )
+        .is_err());
+        let every = Duration::parse("1d");
+        assert!(naive_date_range(
+            "a",
+            date("2022-01-02"),
+            date("2022-01-01"),
+            every,
+            ClosedWindow::Both
+        )
+        .is_err());
+        Ok(())
+    }
+}
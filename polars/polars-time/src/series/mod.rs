@@ -322,9 +322,15 @@ pub trait TemporalMethods: AsSeries {
         let s = self.as_series();
         match s.dtype() {
             #[cfg(feature = "dtype-date")]
-            DataType::Date => s.date().map(|ca| ca.strftime(fmt).into_series()),
+            DataType::Date => s
+                .date()
+                .and_then(|ca| ca.try_strftime(fmt))
+                .map(|ca| ca.into_series()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.strftime(fmt).into_series()),
+            DataType::Datetime(_, _) => s
+                .datetime()
+                .and_then(|ca| ca.try_strftime(fmt))
+                .map(|ca| ca.into_series()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => s.time().map(|ca| ca.strftime(fmt).into_series()),
             _ => Err(PolarsError::InvalidOperation(
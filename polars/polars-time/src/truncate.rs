@@ -3,9 +3,51 @@ use polars_core::prelude::*;
 
 use crate::prelude::*;
 
+/// Calendar unit to truncate temporal values to, similar to the field argument of SQL's
+/// `DATE_TRUNC`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TruncateUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Truncate to the Monday of the week.
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TruncateUnit {
+    /// The [`Duration`] that spans one unit.
+    pub fn to_duration(self) -> Duration {
+        let duration = match self {
+            TruncateUnit::Second => "1s",
+            TruncateUnit::Minute => "1m",
+            TruncateUnit::Hour => "1h",
+            TruncateUnit::Day => "1d",
+            TruncateUnit::Week => "1w",
+            TruncateUnit::Month => "1mo",
+            TruncateUnit::Quarter => "3mo",
+            TruncateUnit::Year => "1y",
+        };
+        Duration::parse(duration)
+    }
+}
+
 pub trait PolarsTruncate {
     #[must_use]
     fn truncate(&self, every: Duration, offset: Duration) -> Self;
+
+    /// Floor every value to the start of its `unit`. Month, quarter and year truncation
+    /// are calendar aware. Nulls are propagated.
+    #[must_use]
+    fn truncate_to(&self, unit: TruncateUnit) -> Self
+    where
+        Self: Sized,
+    {
+        self.truncate(unit.to_duration(), Duration::new(0))
+    }
 }
 
 #[cfg(feature = "dtype-datetime")]
@@ -41,3 +83,40 @@ impl PolarsTruncate for DateChunked {
         .into_date()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_truncate_to() {
+        let dt = NaiveDate::from_ymd_opt(2022, 8, 18)
+            .unwrap()
+            .and_hms_opt(13, 45, 30)
+            .unwrap();
+        let ca = DatetimeChunked::from_naive_datetime_options(
+            "a",
+            [Some(dt), None],
+            TimeUnit::Milliseconds,
+        );
+        let fmt = |unit| {
+            Vec::from(&ca.truncate_to(unit).strftime("%Y-%m-%d %H:%M:%S"))
+                .into_iter()
+                .map(|s| s.map(|s| s.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let expected = |s: &str| vec![Some(s.to_string()), None];
+
+        assert_eq!(fmt(TruncateUnit::Minute), expected("2022-08-18 13:45:00"));
+        assert_eq!(fmt(TruncateUnit::Hour), expected("2022-08-18 13:00:00"));
+        assert_eq!(fmt(TruncateUnit::Day), expected("2022-08-18 00:00:00"));
+        // 2022-08-18 is a thursday
+        assert_eq!(fmt(TruncateUnit::Week), expected("2022-08-15 00:00:00"));
+        assert_eq!(fmt(TruncateUnit::Month), expected("2022-08-01 00:00:00"));
+        assert_eq!(fmt(TruncateUnit::Quarter), expected("2022-07-01 00:00:00"));
+        assert_eq!(fmt(TruncateUnit::Year), expected("2022-01-01 00:00:00"));
+    }
+}
@@ -177,6 +177,8 @@
 //! * `strings` - Extra string utilities for `Utf8Chunked`
 //!     - `string_justify` - `zfill`, `ljust`, `rjust`
 //!     - `string_from_radix` - `parse_int`
+//!     - `string_normalize` - `normalize_unicode`
+//!     - `string_url` - `url_encode`, `url_decode`
 //! * `object` - Support for generic ChunkedArrays called `ObjectChunked<T>` (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -192,7 +194,9 @@
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
+//!     - `to_json` - Serialize the values of a `Series` to a JSON array and parse a `DataFrame` from JSON records.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `delta` - Write Delta Lake tables
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csv-files and decompress them.
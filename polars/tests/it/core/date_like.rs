@@ -159,3 +159,70 @@ fn test_duration() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-duration")]
+fn test_duration_totals() -> PolarsResult<()> {
+    let a = Int64Chunked::new("", &[1_500, 3_000])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let b = Int64Chunked::new("", &[Some(0), None])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let diff = a.subtract(&b)?;
+    let diff = diff.duration()?;
+    assert_eq!(Vec::from(&diff.total_seconds()), &[Some(1.5), None]);
+    assert_eq!(Vec::from(&diff.total_milliseconds()), &[Some(1500.0), None]);
+    assert_eq!(Vec::from(&diff.total_microseconds()), &[Some(1.5e6), None]);
+    assert_eq!(
+        Vec::from(&diff.total_nanoseconds()),
+        &[Some(1_500_000_000), None]
+    );
+    assert_eq!(Vec::from(&diff.seconds()), &[Some(1), None]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_parse_dates() -> PolarsResult<()> {
+    let ca = Utf8Chunked::new(
+        "a",
+        &[None, Some("12/31/2021"), Some("01/02/2022"), Some("foo")],
+    );
+    let out = ca.parse_dates(None)?;
+    assert_eq!(out.name(), "a");
+    assert_eq!(
+        Vec::from(&out.strftime("%Y-%m-%d")),
+        &[None, Some("2021-12-31"), Some("2022-01-02"), None]
+    );
+
+    let ca = Utf8Chunked::new("a", &["2021-12-31", "31-12-2021"]);
+    assert_eq!(ca.parse_dates(None)?.null_count(), 1);
+    assert_eq!(ca.parse_dates(Some("%d-%m-%Y"))?.null_count(), 1);
+
+    let ca = Utf8Chunked::new("a", &[None::<&str>, None]);
+    assert_eq!(ca.parse_dates(None)?.null_count(), 2);
+    assert!(Utf8Chunked::new("a", &["foo"]).parse_dates(None).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+fn test_try_strftime() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(0i32), None, Some(365)]).cast(&DataType::Date)?;
+    let out = s.date()?.try_strftime("%d/%m/%Y")?;
+    assert_eq!(out.name(), "a");
+    assert_eq!(
+        Vec::from(&out),
+        &[Some("01/01/1970"), None, Some("01/01/1971")]
+    );
+    assert!(s.date()?.try_strftime("%Y-%Q").is_err());
+
+    let s = Series::new("a", &[Some(86_400_000i64), None])
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    let out = s.datetime()?.try_strftime("%Y-%m-%d %H:%M")?;
+    assert_eq!(Vec::from(&out), &[Some("1970-01-02 00:00"), None]);
+    assert!(s.datetime()?.try_strftime("%").is_err());
+    assert!(s.strftime("%Q").is_err());
+    Ok(())
+}
@@ -161,6 +161,52 @@ fn test_join_with_nulls() {
     );
 }
 
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+    let out = temp.semi_join(&rain, ["days"], ["days"])?;
+    assert_eq!(out.get_column_names(), temp.get_column_names());
+    assert_eq!(Vec::from(out.column("days")?.i32()?), &[Some(1), Some(2)]);
+    let out = temp.anti_join(&rain, ["days"], ["days"])?;
+    assert_eq!(Vec::from(out.column("days")?.i32()?), &[Some(0)]);
+
+    let (left, right) = get_dfs();
+    let semi = left.semi_join(&right, ["a", "b"], ["foo", "bar"])?;
+    assert_eq!(
+        Vec::from(semi.column("c")?.i32()?),
+        &[Some(0), Some(2), Some(3)]
+    );
+    let anti = left.anti_join(&right, ["a", "b"], ["foo", "bar"])?;
+    assert_eq!(Vec::from(anti.column("c")?.i32()?), &[Some(1)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_cartesian_filter() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 1, 3],
+        "b" => ["x", "y", "z", "x"],
+    ]?;
+    // duplicated reference rows must not duplicate the output rows
+    let reference = df![
+        "key_a" => [1, 1, 3, 3],
+        "key_b" => ["x", "x", "y", "x"],
+    ]?;
+    let out = df.cartesian_filter(&reference, &["a", "b"], &["key_a", "key_b"])?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+    assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("x"), Some("x")]);
+
+    let out = df.cartesian_filter(&reference, &["a"], &["key_a"])?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(1), Some(3)]
+    );
+    Ok(())
+}
+
 fn get_dfs() -> (DataFrame, DataFrame) {
     let df_a = df! {
         "a" => &[1, 2, 1, 1],
@@ -615,3 +661,74 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+fn test_join_on_index() -> PolarsResult<()> {
+    let left = df![
+        "key" => [1, 2, 3],
+        "a" => ["x", "y", "z"],
+    ]?;
+    let right = df![
+        "key" => [3, 1],
+        "b" => [30, 10],
+    ]?;
+    let left_idx = IdxCa::new("", &[Some(0 as IdxSize), Some(2), Some(1)]);
+    let right_idx = IdxCa::new("", &[Some(1 as IdxSize), Some(0), None]);
+
+    let out = DataFrame::join_on_index(&left, &right, &left_idx, &right_idx)?;
+    assert_eq!(out.get_column_names(), &["key", "a", "key_right", "b"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.utf8()?),
+        &[Some("x"), Some("z"), Some("y")]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), Some(30), None]
+    );
+
+    let short = IdxCa::new("", &[0 as IdxSize]);
+    assert!(DataFrame::join_on_index(&left, &right, &left_idx, &short).is_err());
+    let out_of_bounds = IdxCa::new("", &[5 as IdxSize, 0, 0]);
+    assert!(DataFrame::join_on_index(&left, &right, &out_of_bounds, &right_idx).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_join_indices() {
+    let left = Int32Chunked::new("l", &[1, 2, 3, 2]);
+    let right = Int32Chunked::new("r", &[2, 4, 1]);
+    let pairs = |(l, r): (IdxCa, IdxCa)| {
+        let mut pairs = l.into_iter().zip(r.into_iter()).collect::<Vec<_>>();
+        pairs.sort();
+        pairs
+    };
+
+    assert_eq!(
+        pairs(Int32Chunked::inner_join_indices(&left, &right)),
+        &[(Some(0), Some(2)), (Some(1), Some(0)), (Some(3), Some(0))]
+    );
+
+    let (left_idx, right_idx) = Int32Chunked::left_join_indices(&left, &right);
+    assert_eq!(left_idx.name(), "l");
+    assert_eq!(right_idx.name(), "r");
+    assert_eq!(
+        pairs((left_idx, right_idx)),
+        &[
+            (Some(0), Some(2)),
+            (Some(1), Some(0)),
+            (Some(2), None),
+            (Some(3), Some(0))
+        ]
+    );
+
+    assert_eq!(
+        pairs(Int32Chunked::outer_join_indices(&left, &right)),
+        &[
+            (None, Some(1)),
+            (Some(0), Some(2)),
+            (Some(1), Some(0)),
+            (Some(2), None),
+            (Some(3), Some(0))
+        ]
+    );
+}
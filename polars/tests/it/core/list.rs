@@ -14,3 +14,31 @@ fn test_to_list_logical() -> PolarsResult<()> {
     assert_eq!(expl.dtype(), &DataType::Date);
     Ok(())
 }
+
+#[test]
+fn test_list_pad_to_length() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new("a", 3, 6, DataType::Int32);
+    builder.append_slice(&[1, 2, 3, 4]);
+    builder.append_null();
+    builder.append_slice(&[5]);
+    let ca = builder.finish();
+
+    let out = ca.lst_pad_to_length::<Int32Type>(2, Some(0))?;
+    assert_eq!(Vec::from(&out.lst_lengths()), &[Some(2), Some(0), Some(2)]);
+    let out = out.into_series().explode()?;
+    let out = out.i32()?;
+    assert_eq!(Vec::from(out), &[Some(1), Some(2), None, Some(5), Some(0)]);
+
+    let out = ca.lst_pad_to_length::<Int32Type>(3, None)?;
+    let out = out.into_series().explode()?;
+    let out = out.i32()?;
+    assert_eq!(
+        Vec::from(out),
+        &[Some(1), Some(2), Some(3), None, Some(5), None, None]
+    );
+
+    assert!(ca.lst_pad_to_length::<Float64Type>(2, Some(0.0)).is_err());
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use polars::export::chrono::{NaiveDate, NaiveDateTime};
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{pivot, pivot_from_indices, pivot_stable, PivotAgg};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -168,3 +168,22 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_from_indices() -> PolarsResult<()> {
+    let values = Series::new("values", &[1, 2, 3, 4, 5]);
+    let row_idx = UInt32Chunked::new("", &[0, 1, 0, 2, 0]);
+    let col_idx = UInt32Chunked::new("", &[0, 0, 1, 1, 1]);
+
+    let out = pivot_from_indices(&values, &row_idx, &col_idx, 3, 2, &["a", "b"])?;
+    let expected = df![
+        "a" => [Some(1), Some(2), None],
+        "b" => [Some(5), None, Some(4)]
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    let col_idx = UInt32Chunked::new("", &[0, 0, 1, 2, 1]);
+    assert!(pivot_from_indices(&values, &row_idx, &col_idx, 3, 2, &["a", "b"]).is_err());
+
+    Ok(())
+}
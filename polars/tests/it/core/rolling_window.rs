@@ -428,3 +428,88 @@ fn test_median_quantile_types() {
     assert_eq!(*rol_quantile.dtype(), DataType::Float64);
     assert_eq!(*rol_quantile_weighted.dtype(), DataType::Float64);
 }
+
+#[test]
+fn test_rolling_zscore() -> PolarsResult<()> {
+    let ca = Int32Chunked::new("foo", &[Some(1), Some(2), Some(3), Some(3), None, Some(5)]);
+    let out = ca.rolling_zscore(3, 2, 1)?;
+    let expected = [
+        None,
+        Some(0.5f64.sqrt()),
+        Some(1.0),
+        Some(1.0 / 3.0f64.sqrt()),
+        None,
+        Some(0.5f64.sqrt()),
+    ];
+    assert_eq!(out.len(), expected.len());
+    for (a, b) in out.into_iter().zip(expected) {
+        match (a, b) {
+            (Some(a), Some(b)) => assert!((a - b).abs() < 1e-10),
+            (a, b) => assert_eq!(a, b),
+        }
+    }
+
+    // zero standard deviation
+    let ca = Float64Chunked::new("foo", &[2.0, 2.0, 2.0]);
+    assert_eq!(ca.rolling_zscore(2, 1, 0)?.null_count(), 3);
+    assert!(ca.rolling_zscore(0, 0, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_window_var_std() -> PolarsResult<()> {
+    // a large mean and a small variance, where the sum of squares formula cancels
+    let offset = 1e9;
+    let ca = Float64Chunked::new(
+        "foo",
+        &[
+            Some(offset + 4.0),
+            Some(offset + 7.0),
+            None,
+            Some(offset + 13.0),
+            Some(offset + 16.0),
+        ],
+    );
+    let out = ca.window_var(4, 2, 1)?;
+    let expected = [None, Some(4.5), Some(4.5), Some(21.0), Some(21.0)];
+    assert_eq!(out.name(), "foo");
+    for (a, b) in out.into_iter().zip(expected) {
+        match (a, b) {
+            (Some(a), Some(b)) => assert!((a - b).abs() < 1e-6, "{a} != {b}"),
+            (a, b) => assert_eq!(a, b),
+        }
+    }
+    let out = ca.window_std(5, 4, 1)?;
+    assert_eq!(out.null_count(), 4);
+    assert!((out.get(4).unwrap() - 30f64.sqrt()).abs() < 1e-6);
+
+    let ca = Int32Chunked::new("foo", &[1, 2]);
+    assert_eq!(ca.window_var(2, 1, 1)?.get(0), None);
+    assert!(ca.window_var(0, 0, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rolling_apply_to_f64() -> PolarsResult<()> {
+    let ca = Int32Chunked::new("foo", &[Some(1), None, Some(3), Some(4)]);
+    fn masked_sum(values: &[i32], valid: &[bool]) -> f64 {
+        values
+            .iter()
+            .zip(valid)
+            .filter(|(_, valid)| **valid)
+            .map(|(v, _)| *v as f64)
+            .sum()
+    }
+    let out = ca.rolling_apply_to_f64(2, 1, masked_sum)?;
+    assert_eq!(out.name(), "foo");
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(1.0), Some(1.0), Some(3.0), Some(7.0)]
+    );
+    let out = ca.rolling_apply_to_f64(3, 2, |values, _| values.len() as f64)?;
+    assert_eq!(Vec::from(&out), &[None, None, Some(3.0), Some(3.0)]);
+
+    assert!(ca.rolling_apply_to_f64(0, 0, masked_sum).is_err());
+    assert!(ca.rolling_apply_to_f64(2, 3, masked_sum).is_err());
+    Ok(())
+}
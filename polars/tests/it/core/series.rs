@@ -36,3 +36,243 @@ fn test_min_max_sorted_desc() {
     assert_eq!(a.max(), Some(4));
     assert_eq!(a.min(), Some(1));
 }
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_format_columns() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let name = Series::new("name", &[Some("a"), Some("b"), None]);
+    let id = Series::new("id", &[1, 2, 3]);
+    let out = format_columns("{name}_{id} {x}", &[("name", &name), ("id", &id)])?;
+    assert_eq!(Vec::from(&out), &[Some("a_1 {x}"), Some("b_2 {x}"), None]);
+
+    let out = name.utf8()?.format_with_template("<{name}>", "name")?;
+    assert_eq!(Vec::from(&out), &[Some("<a>"), Some("<b>"), None]);
+    assert!(format_columns("{name}", &[("id", &id)]).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_encoding")]
+fn test_hex_utf8() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new("a", &[Some("foo"), None, Some("")]);
+    let encoded = ca.hex_encode();
+    assert_eq!(Vec::from(&encoded), &[Some("666f6f"), None, Some("")]);
+    assert!(encoded
+        .hex_decode_utf8()?
+        .into_series()
+        .series_equal_missing(&ca.into_series()));
+
+    let ca = Utf8Chunked::new("a", &["666f6", "zz", "ff"]);
+    for s in ca.into_no_null_iter() {
+        assert!(Utf8Chunked::new("a", &[s]).hex_decode_utf8().is_err());
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_encoding")]
+fn test_base64_utf8() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new("a", &[Some("foo?>"), None, Some("")]);
+    let encoded = ca.base64_encode();
+    assert_eq!(Vec::from(&encoded), &[Some("Zm9vPz4="), None, Some("")]);
+    let decoded = encoded.base64_decode_utf8()?;
+    assert!(decoded
+        .into_series()
+        .series_equal_missing(&ca.clone().into_series()));
+
+    let encoded = ca.base64_encode_url_safe();
+    assert_eq!(Vec::from(&encoded), &[Some("Zm9vPz4="), None, Some("")]);
+    let ca = Utf8Chunked::new("a", &["???"]);
+    let encoded = ca.base64_encode_url_safe();
+    assert_eq!(Vec::from(&encoded), &[Some("Pz8_")]);
+    assert_eq!(
+        Vec::from(&encoded.base64_decode_url_safe_utf8()?),
+        &[Some("???")]
+    );
+    assert!(encoded.base64_decode_utf8().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_string_validation() {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new(
+        "a",
+        &[
+            Some("jane.doe+tag@example.co.uk"),
+            Some("https://example.com:8080/a?b=c#d"),
+            Some("192.168.0.1"),
+            Some("::1"),
+            Some("not an@email.com"),
+            None,
+        ],
+    );
+    let email = ca.is_email();
+    assert_eq!(email.name(), "a");
+    assert_eq!(
+        Vec::from(&email),
+        &[
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(&ca.is_url()),
+        &[
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(&ca.is_ip_address()),
+        &[
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(false),
+            None
+        ]
+    );
+    let ca = Utf8Chunked::new("a", &["a@localhost", "ftp://host", "256.0.0.1"]);
+    assert_eq!(Vec::from(&ca.is_email()), &[Some(false); 3]);
+    assert_eq!(
+        Vec::from(&ca.is_url()),
+        &[Some(false), Some(true), Some(false)]
+    );
+    assert_eq!(Vec::from(&ca.is_ip_address()), &[Some(false); 3]);
+}
+
+#[test]
+#[cfg(feature = "string_url")]
+fn test_url_encode_decode() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new("a", &[Some("a b/c?d=é&f#g%"), None, Some("")]);
+    let expected = [
+        (UrlEncodeSet::Path, "a%20b/c%3Fd=%C3%A9&f%23g%25"),
+        (UrlEncodeSet::Query, "a%20b/c?d=%C3%A9&f%23g%25"),
+        (UrlEncodeSet::Fragment, "a%20b/c?d=%C3%A9&f#g%25"),
+        (UrlEncodeSet::Form, "a+b%2Fc%3Fd%3D%C3%A9%26f%23g%25"),
+    ];
+    for (set, encoded) in expected {
+        let out = ca.url_encode(set);
+        assert_eq!(Vec::from(&out), &[Some(encoded), None, Some("")]);
+        assert!(out
+            .url_decode(set)?
+            .into_series()
+            .series_equal_missing(&ca.clone().into_series()));
+    }
+
+    let ca = Utf8Chunked::new("a", &["a+b%2B"]);
+    assert_eq!(
+        Vec::from(&ca.url_decode(UrlEncodeSet::Query)?),
+        &[Some("a+b+")]
+    );
+    assert_eq!(
+        Vec::from(&ca.url_decode(UrlEncodeSet::Form)?),
+        &[Some("a b+")]
+    );
+    for s in ["%", "%2", "%zz", "%ff"] {
+        let ca = Utf8Chunked::new("a", &[s]);
+        assert!(ca.url_decode(UrlEncodeSet::Path).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_contains_any() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new("a", &[Some("Foo bar"), Some("baz"), Some("qux 42"), None]);
+    let out = ca.contains_any(&["foo", r"\d+"], false)?;
+    assert_eq!(out.name(), "a");
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(false), Some(false), Some(true), None]
+    );
+
+    let out = ca.contains_any(&["foo", r"\d+"], true)?;
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(true), Some(false), Some(true), None]
+    );
+
+    let out = ca.contains_any(&[], false)?;
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(false), Some(false), Some(false), None]
+    );
+    assert!(ca.contains_any(&["("], false).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_split_to_columns() -> PolarsResult<()> {
+    use polars_ops::prelude::*;
+
+    let ca = Utf8Chunked::new("a", &[Some("a-b-c"), Some("d"), None, Some("e-f")]);
+    let df = ca.split_to_columns("-", 2, &["x", "y"])?;
+    assert_eq!(df.get_column_names(), &["x", "y"]);
+    assert_eq!(
+        Vec::from(df.column("x")?.utf8()?),
+        &[Some("a"), Some("d"), None, Some("e")]
+    );
+    assert_eq!(
+        Vec::from(df.column("y")?.utf8()?),
+        &[Some("b"), None, None, Some("f")]
+    );
+    assert!(ca.split_to_columns("-", 2, &["x"]).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "row_hash")]
+fn test_hash_array() {
+    use polars_ops::prelude::*;
+
+    let s = Series::new("a", &[Some(1i64), None, Some(1), Some(2)]);
+    let out = s.hash_array(42);
+    assert_eq!(out.name(), "a");
+    assert_eq!(out.null_count(), 0);
+    assert_eq!(out.get(1), Some(NULL_HASH));
+    assert_eq!(out.get(0), out.get(2));
+    assert_ne!(out.get(0), out.get(3));
+    assert_eq!(Vec::from(&out), Vec::from(&s.hash_array(42)));
+
+    let s = Series::new("a", &[Some("x"), None]);
+    assert_eq!(s.hash_array(0).get(1), Some(NULL_HASH));
+    assert_eq!(s.hash_array(1).get(1), Some(NULL_HASH));
+}
+
+#[test]
+fn test_series_quantiles() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(3.0f32), None, Some(1.0), Some(2.0)]);
+    assert_eq!(
+        s.quantiles(&[0.0, 0.25, 1.0], QuantileInterpolOptions::Linear)?,
+        &[Some(1.0), Some(1.5), Some(3.0)]
+    );
+    let s = Series::new("a", &["x", "y"]);
+    assert!(s
+        .quantiles(&[0.5], QuantileInterpolOptions::Linear)
+        .is_err());
+    Ok(())
+}
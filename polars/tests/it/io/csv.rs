@@ -27,6 +27,64 @@ fn write_csv() {
     assert_eq!("0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n", csv);
 }
 
+#[test]
+fn write_csv_with_options() {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut df = df![
+        "a" => [Some(1), None, Some(3)],
+        "b" => [Some("x;y"), Some("z"), None]
+    ]
+    .unwrap();
+
+    let options = CsvWriteOptions {
+        delimiter: b';',
+        has_header: false,
+        null_value: "NA".to_string(),
+        ..Default::default()
+    };
+    CsvWriter::new(&mut buf)
+        .with_options(options)
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("1;\"x;y\"\nNA;z\n3;NA\n", csv);
+}
+
+#[test]
+fn write_csv_to_writer() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [Some("x"), None, Some("z")]
+    ]?;
+    let options = CsvWriteOptions {
+        null_value: "null".to_string(),
+        ..Default::default()
+    };
+    let mut buf: Vec<u8> = Vec::new();
+    df.write_csv(&mut buf, options)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("a,b\n1,x\n2,null\n3,z\n", csv);
+    Ok(())
+}
+
+#[test]
+fn write_csv_custom_quote_char() {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut df = df![
+        "a" => ["it's", "", "x;y", "z"]
+    ]
+    .unwrap();
+
+    CsvWriter::new(&mut buf)
+        .has_header(false)
+        .with_delimiter(b';')
+        .with_quoting_char(b'\'')
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("'it''s'\n''\n'x;y'\nz\n", csv);
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -709,6 +767,37 @@ fn test_ignore_parse_dates() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_override() -> PolarsResult<()> {
+    let csv = "zip,count
+01234,1
+00042,2
+";
+    let overrides = [
+        ("zip".to_string(), DataType::Utf8),
+        ("count".to_string(), DataType::Float64),
+        ("not_a_column".to_string(), DataType::Float64),
+    ]
+    .into_iter()
+    .collect::<PlHashMap<_, _>>();
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_schema_override(overrides.clone())
+        .finish()?;
+
+    assert_eq!(df.dtypes(), &[DataType::Utf8, DataType::Float64]);
+    assert_eq!(df.column("zip")?, &Series::new("zip", ["01234", "00042"]));
+
+    // the batched reader respects the overrides as well
+    let file = Cursor::new(csv);
+    let mut reader = CsvReader::new(file).with_schema_override(overrides);
+    let mut batched = reader.batched_borrowed()?;
+    let batches = batched.next_batches(1)?.unwrap();
+    assert_eq!(batches[0].1.dtypes(), &[DataType::Utf8, DataType::Float64]);
+    Ok(())
+}
+
 #[test]
 fn test_projection_and_quoting() -> PolarsResult<()> {
     let csv = "a,b,c,d
@@ -1078,3 +1167,15 @@ fn test_parse_dates_3380() -> PolarsResult<()> {
     assert_eq!(df.column("validdate")?.null_count(), 0);
     Ok(())
 }
+
+#[test]
+fn test_from_bytes() -> PolarsResult<()> {
+    let csv = b"a,b\n1,x\n2,\n";
+    let df = CsvReader::from_bytes(csv).has_header(true).finish()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => [Some("x"), None],
+    ]?;
+    assert!(df.frame_equal_missing(&expected));
+    Ok(())
+}
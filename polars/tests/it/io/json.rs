@@ -213,3 +213,44 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_write_ndjson() -> PolarsResult<()> {
+    let mut df = df!(
+        "a" => [Some(1i64), None],
+        "b" => [Some("x"), None],
+    )?;
+    df.vstack_mut(&df!(
+        "a" => [Some(3i64)],
+        "b" => [Some("z")],
+    )?)?;
+    let list = Series::new("c", [Series::new("", [1i32, 2]), Series::new("", [3i32])]);
+
+    let mut buf = vec![];
+    df.write_ndjson(&mut buf)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "{\"a\":1,\"b\":\"x\"}\n{\"a\":null,\"b\":null}\n{\"a\":3,\"b\":\"z\"}\n"
+    );
+
+    let mut buf = vec![];
+    DataFrame::new(vec![list])?.write_ndjson(&mut buf)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "{\"c\":[1,2]}\n{\"c\":[3]}\n"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_write_ndjson_date() -> PolarsResult<()> {
+    let date = Series::new("d", [1i32]).cast(&DataType::Date)?;
+    let mut buf = vec![];
+    DataFrame::new(vec![date])?.write_ndjson(&mut buf)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "{\"d\":\"1970-01-02\"}\n"
+    );
+    Ok(())
+}
@@ -32,3 +32,48 @@ fn test_scan_parquet_files() -> PolarsResult<()> {
     assert_eq!(df.shape(), (54, 4));
     Ok(())
 }
+
+#[test]
+fn test_read_parquet_with_predicate_prunes_row_groups() -> PolarsResult<()> {
+    use polars::io::parquet::predicates::BatchStats;
+    use polars::io::predicates::{PhysicalIoExpr, StatsEvaluator};
+
+    // `a >= 7`, but the row filter lets everything through so that we can observe
+    // which row groups were skipped based on their statistics.
+    struct MinA;
+
+    impl PhysicalIoExpr for MinA {
+        fn evaluate(&self, df: &DataFrame) -> PolarsResult<Series> {
+            Ok(BooleanChunked::full("", true, df.height()).into_series())
+        }
+
+        fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+            Some(self)
+        }
+    }
+
+    impl StatsEvaluator for MinA {
+        fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+            let min_max = stats.get_stats("a")?.to_min_max().unwrap();
+            Ok(min_max.i32()?.get(1).unwrap() >= 7)
+        }
+    }
+
+    let mut df = df! {
+        "a" => (0..10).collect::<Vec<i32>>()
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_statistics(true)
+        .with_row_group_size(Some(5))
+        .finish(&mut df)?;
+
+    let read_df = ParquetReader::new(buf)
+        .with_predicate(Some(Arc::new(MinA)))
+        .finish()?;
+    assert_eq!(
+        Vec::from(read_df.column("a")?.i32()?),
+        (5..10).map(Some).collect::<Vec<_>>()
+    );
+    Ok(())
+}
@@ -829,7 +829,7 @@ impl PyDataFrame {
     ) -> PyResult<Self> {
         let df = self
             .df
-            .sample_n(n, with_replacement, shuffle, seed)
+            .sample_n(n, with_replacement, shuffle, seed, None)
             .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }